@@ -2,7 +2,7 @@ use super::model::{AccountArgs, AccountOptions};
 use crate::{constants::PLATFORM_MAP, utility::account::AccountUtility};
 pub struct AccountCommand {}
 impl AccountCommand {
-    pub fn handle(args: AccountArgs) -> Result<String, String> {
+    pub async fn handle(args: AccountArgs) -> Result<String, String> {
         let platform = args.platform;
         let real_platform = match platform {
             Some(platform) => match PLATFORM_MAP.get(platform.as_str()) {
@@ -54,6 +54,16 @@ impl AccountCommand {
                     }
                 };
             }
+            AccountOptions::ImportCookies { file } => {
+                match AccountUtility::import_cookies(real_platform, file).await {
+                    Ok(username) => {
+                        return Ok(format!("Cookies of account {} imported.", username));
+                    }
+                    Err(info) => {
+                        return Err(info);
+                    }
+                }
+            }
         }
     }
 }
@@ -0,0 +1,61 @@
+use colored::Colorize;
+use prettytable::table;
+
+use super::model::{CacheArgs, CacheOptions};
+use crate::utility::page_cache::PageCache;
+
+pub struct CacheCommand {}
+
+impl CacheCommand {
+    pub fn handle(args: CacheArgs) -> Result<String, String> {
+        match args.options {
+            CacheOptions::Clear => Self::clear(),
+            CacheOptions::Stats => Self::stats(),
+        }
+    }
+
+    fn clear() -> Result<String, String> {
+        let count = match PageCache::clear() {
+            Ok(count) => count,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        println!("Removed {} cached pages.", count.to_string().green());
+        return Ok(String::from("Cache clear success"));
+    }
+
+    fn stats() -> Result<String, String> {
+        let cache_dir = match PageCache::get_cache_dir() {
+            Ok(cache_dir) => cache_dir,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        let stats = match PageCache::stats() {
+            Ok(stats) => stats,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        let oldest = match stats.oldest {
+            Some(oldest) => match chrono::DateTime::from_timestamp(oldest, 0) {
+                Some(oldest) => oldest
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string(),
+                None => String::new(),
+            },
+            None => String::new(),
+        };
+        let mut table = table!(
+            ["directory", cache_dir.display()],
+            ["pages", stats.entries],
+            ["size", format!("{:.1} KB", stats.size as f64 / 1024.0)],
+            ["oldest", oldest]
+        );
+        table.set_format(*prettytable::format::consts::FORMAT_CLEAN);
+        table.printstd();
+        return Ok(String::from("Cache stats success"));
+    }
+}
@@ -0,0 +1,117 @@
+use chrono::Local;
+use prettytable::{row, Table};
+use strum::IntoEnumIterator;
+use tokio::fs;
+
+use super::model::ContestsArgs;
+use crate::constants::PLATFORM_MAP;
+use crate::database::CONFIG_DB;
+use crate::model::{AccountInfo, Contest, ContestStatus, Platform};
+use crate::platform::error::OnlineJudgeError;
+use crate::platform::OnlineJudge;
+use crate::utility::calendar::Calendar;
+
+pub struct ContestsCommand {}
+
+impl ContestsCommand {
+    pub async fn handle(args: ContestsArgs) -> Result<String, OnlineJudgeError> {
+        let platforms = match args.platform {
+            Some(platform) => match PLATFORM_MAP.get(platform.as_str()) {
+                Some(platform) => vec![*platform],
+                None => {
+                    return Err(format!("Platform {} not found", platform).into());
+                }
+            },
+            None => Platform::iter().collect(),
+        };
+        let mut contests = Vec::new();
+        let mut last_error = None;
+        for platform in platforms {
+            // Anonymous access is enough to list contests, but registration state needs login.
+            let account_info = match CONFIG_DB.get_default_account(platform) {
+                Ok(account_info) => account_info,
+                Err(_) => AccountInfo {
+                    username: String::new(),
+                    password: String::new(),
+                    cookies: String::new(),
+                    current: 0,
+                    last_use: String::new(),
+                },
+            };
+            let mut oj = match OnlineJudge::new(account_info, platform) {
+                Ok(oj) => oj,
+                Err(info) => {
+                    log::warn!("Get contests of {} failed, {}", platform, info);
+                    last_error = Some(info.into());
+                    continue;
+                }
+            };
+            match oj.get_contests().await {
+                Ok(platform_contests) => {
+                    contests.extend(platform_contests.into_iter().map(|x| (platform, x)));
+                }
+                // Contests of other platforms are still listed.
+                Err(info) => {
+                    log::warn!("Get contests of {} failed, {}", platform, info);
+                    last_error = Some(info);
+                }
+            }
+        }
+        if contests.is_empty() {
+            if let Some(info) = last_error {
+                return Err(info);
+            }
+        }
+        contests.sort_by_key(|(_, contest)| contest.start_time);
+        Self::show_contests(&contests);
+        if let Some(ics_path) = args.ics {
+            if let Err(info) = fs::write(&ics_path, Calendar::to_ics(&contests)).await {
+                return Err(format!("Write {} failed, {}", ics_path, info).into());
+            }
+            println!("Export {} contests to {}", contests.len(), ics_path);
+        }
+        return Ok(String::from("Contests command success"));
+    }
+
+    fn show_contests(contests: &[(Platform, Contest)]) {
+        let mut table = Table::new();
+        table.add_row(row![
+            "platform",
+            "contest",
+            "title",
+            "start time",
+            "duration",
+            "status",
+            "registration"
+        ]);
+        for (platform, contest) in contests {
+            let duration = contest.end_time - contest.start_time;
+            let status = match contest.status {
+                ContestStatus::NotStarted => "Upcoming",
+                ContestStatus::Running => "Running",
+                ContestStatus::Ended => "Ended",
+            };
+            let registration = match contest.registration.is_empty() {
+                true => "-",
+                false => contest.registration.as_str(),
+            };
+            table.add_row(row![
+                platform,
+                contest.identifier,
+                contest.title,
+                contest
+                    .start_time
+                    .with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M"),
+                format!(
+                    "{}:{:02}",
+                    duration.num_hours(),
+                    duration.num_minutes() % 60
+                ),
+                status,
+                registration
+            ]);
+        }
+        table.printstd();
+    }
+}
@@ -0,0 +1,183 @@
+use std::path;
+use tokio::fs;
+
+use colored::Colorize;
+
+use super::model::FetchSubmissionArgs;
+use crate::constants::PLATFORM_MAP;
+use crate::database::CONFIG_DB;
+use crate::model::{Platform, PostSubmissionInfo, SubmissionSource};
+use crate::platform::error::OnlineJudgeError;
+use crate::platform::OnlineJudge;
+use crate::utility::language::LanguageUtility;
+use crate::utility::Utility;
+
+pub struct FetchSubmissionCommand {}
+
+impl FetchSubmissionCommand {
+    pub async fn handle(args: FetchSubmissionArgs) -> Result<String, OnlineJudgeError> {
+        let (platform, contest_identifier) = match (args.platform, args.contest_identifier) {
+            (Some(platform), Some(contest_identifier)) => {
+                match PLATFORM_MAP.get(platform.as_str()) {
+                    Some(platform) => (*platform, contest_identifier),
+                    None => {
+                        return Err(format!("Platform {} not found", platform).into());
+                    }
+                }
+            }
+            _ => match Utility::get_identifiers_from_current_dir() {
+                Ok((platform, contest_identifier, _)) => (platform, contest_identifier),
+                Err(info) => {
+                    return Err(format!(
+                        "Platform and contest are required outside of problem directory, {}",
+                        info
+                    )
+                    .into());
+                }
+            },
+        };
+        let account_info = match CONFIG_DB.get_default_account(platform) {
+            Ok(account_info) => account_info,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let mut oj = match OnlineJudge::new(account_info, platform) {
+            Ok(oj) => oj,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let (submission_info, source) = match oj
+            .fetch_submission(&contest_identifier, &args.submission_id)
+            .await
+        {
+            Ok(submission) => submission,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        let code_path = match Self::save_submission(platform, &submission_info, &source).await {
+            Ok(code_path) => code_path,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        println!(
+            "Save submission {} ({}) to {}",
+            submission_info.submission_id.bright_blue(),
+            submission_info.verdict_info,
+            code_path.display()
+        );
+        return Ok(String::from("Fetch submission command success"));
+    }
+
+    /// Save source code as `submissions/<id>.<suffix>` in problem directory,
+    /// with verdict in `submissions/<id>.json`.
+    async fn save_submission(
+        platform: Platform,
+        submission_info: &PostSubmissionInfo,
+        source: &SubmissionSource,
+    ) -> Result<path::PathBuf, String> {
+        let workspace = match CONFIG_DB.get_config("workspace") {
+            Ok(workspace) => workspace,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        let submission_path = path::Path::new(workspace.as_str())
+            .join(platform.to_string())
+            .join(submission_info.contest_identifier.to_lowercase())
+            .join(submission_info.problem_identifier.to_lowercase())
+            .join("submissions");
+        if fs::create_dir_all(&submission_path).await.is_err() {
+            return Err(String::from("Create submission directory failed"));
+        }
+        let code_path = submission_path.join(format!(
+            "{}.{}",
+            submission_info.submission_id,
+            Self::get_suffix(&submission_info.language)
+        ));
+        if fs::write(&code_path, source.code.as_bytes()).await.is_err() {
+            return Err(String::from("Write source code failed"));
+        }
+        let metadata = serde_json::json!({
+            "submission_id": submission_info.submission_id,
+            "contest_identifier": submission_info.contest_identifier,
+            "problem_identifier": submission_info.problem_identifier,
+            "language": submission_info.language,
+            "verdict": submission_info.verdict_info,
+            "failed_test": submission_info.failed_test,
+            "execute_time": submission_info.execute_time,
+            "execute_memory": submission_info.execute_memory,
+            "submit_time": submission_info.submit_time,
+        });
+        let content = match serde_json::to_string_pretty(&metadata) {
+            Ok(content) => content,
+            Err(info) => {
+                return Err(info.to_string());
+            }
+        };
+        let metadata_path = submission_path.join(format!("{}.json", submission_info.submission_id));
+        if fs::write(metadata_path, content).await.is_err() {
+            return Err(String::from("Write verdict file failed"));
+        }
+        return Ok(code_path);
+    }
+
+    fn get_suffix(language: &str) -> &'static str {
+        return match LanguageUtility::guess_language(language) {
+            Some(language) => LanguageUtility::get_suffix(language),
+            None => "txt",
+        };
+    }
+}
+
+#[test]
+fn test_get_suffix() {
+    assert_eq!(FetchSubmissionCommand::get_suffix("GNU C++20 (64)"), "cpp");
+    assert_eq!(
+        FetchSubmissionCommand::get_suffix("C++ 20 (gcc 12.2)"),
+        "cpp"
+    );
+    assert_eq!(
+        FetchSubmissionCommand::get_suffix("Python 3 (CPython 3.11.4)"),
+        "py"
+    );
+    assert_eq!(
+        FetchSubmissionCommand::get_suffix("JavaScript (Node.js 18.16.1)"),
+        "js"
+    );
+    assert_eq!(FetchSubmissionCommand::get_suffix("GNU C11"), "c");
+    assert_eq!(FetchSubmissionCommand::get_suffix("Brainfuck"), "txt");
+    assert_eq!(
+        FetchSubmissionCommand::get_suffix("Cython (Cython 0.29.34)"),
+        "py"
+    );
+    assert_eq!(
+        FetchSubmissionCommand::get_suffix("Clojure (babashka 1.3.181)"),
+        "txt"
+    );
+    assert_eq!(
+        FetchSubmissionCommand::get_suffix("Crystal (Crystal 1.9.1)"),
+        "txt"
+    );
+    assert_eq!(
+        FetchSubmissionCommand::get_suffix("COBOL (GnuCOBOL(Fixed) 3.1.2)"),
+        "cob"
+    );
+    assert_eq!(
+        FetchSubmissionCommand::get_suffix("Common Lisp (SBCL 2.3.6)"),
+        "lisp"
+    );
+}
+
+#[test]
+fn test_identifier_args_required_together() {
+    use clap::Parser;
+    let parse = |args: &[&str]| super::Cli::try_parse_from(args).is_ok();
+    assert!(parse(&["ace", "fetch-submission", "1"]));
+    assert!(parse(&["ace", "watch", "1", "-p", "cf", "-c", "1868"]));
+    assert!(!parse(&["ace", "fetch-submission", "1", "-p", "cf"]));
+    assert!(!parse(&["ace", "watch", "1", "-c", "1868"]));
+}
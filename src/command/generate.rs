@@ -1,9 +1,13 @@
 use inquire::Select;
-use std::{env::current_dir, str::FromStr};
+use std::{env::current_dir, path::Path, str::FromStr};
 use tokio::fs;
 
 use crate::{
-    constants::ProgramLanguage, context::CONTEXT, database::CONFIG_DB, snippet::Snippet,
+    constants::ProgramLanguage,
+    context::CONTEXT,
+    database::CONFIG_DB,
+    model::{LanguageConfig, Platform},
+    snippet::Snippet,
     utility::Utility,
 };
 
@@ -13,29 +17,6 @@ pub struct GenerateCommand {}
 
 impl GenerateCommand {
     pub async fn handle(args: GenerateArgs) -> Result<String, String> {
-        if let Ok(dir) = current_dir() {
-            if let Some(cur_path) = dir.to_str() {
-                if let Ok(mut context) = CONTEXT.lock() {
-                    context.update(cur_path);
-                }
-            }
-        }
-        let language = match args.language {
-            Some(language) => language,
-            None => match CONFIG_DB.get_config("default-language") {
-                Ok(languge_str) => match ProgramLanguage::from_str(&languge_str) {
-                    Ok(language) => language,
-                    Err(_) => {
-                        println!("Can't convert default language");
-                        return Err("Can't convert default language".to_string());
-                    }
-                },
-                Err(_) => {
-                    println!("Default language not set, run `ace lang set-default` to set default language.");
-                    return Err("Default language not set, run `ace lang set-default`".to_string());
-                }
-            },
-        };
         let absolute_path = match current_dir() {
             Ok(dir) => dir,
             Err(info) => {
@@ -61,6 +42,36 @@ impl GenerateCommand {
                     return Err(info);
                 }
             };
+        let language_config = match Self::get_language_config(args.language, platform) {
+            Ok(language_config) => language_config,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        return Self::generate(&absolute_path, &language_config).await;
+    }
+
+    /// Get the language config to generate code, use default language if `language` is None.
+    pub fn get_language_config(
+        language: Option<ProgramLanguage>,
+        platform: Platform,
+    ) -> Result<LanguageConfig, String> {
+        let language = match language {
+            Some(language) => language,
+            None => match CONFIG_DB.get_config("default-language") {
+                Ok(languge_str) => match ProgramLanguage::from_str(&languge_str) {
+                    Ok(language) => language,
+                    Err(_) => {
+                        println!("Can't convert default language");
+                        return Err("Can't convert default language".to_string());
+                    }
+                },
+                Err(_) => {
+                    println!("Default language not set, run `ace lang set-default` to set default language.");
+                    return Err("Default language not set, run `ace lang set-default`".to_string());
+                }
+            },
+        };
         let language_configs =
             match CONFIG_DB.get_language_config_by_language_and_platform(language, platform) {
                 Ok(configs) => configs,
@@ -80,14 +91,29 @@ impl GenerateCommand {
                 }
             },
         };
+        return Ok(language_config);
+    }
+
+    /// Generate code file from template in the problem directory.
+    pub async fn generate(
+        problem_path: &Path,
+        language_config: &LanguageConfig,
+    ) -> Result<String, String> {
+        let problem_path_str = match problem_path.to_str() {
+            Some(path) => path,
+            None => {
+                return Err("Can't get problem path".to_string());
+            }
+        };
         let filename = format!("code.{}", language_config.suffix);
-        if let Ok(mut contest) = CONTEXT.lock() {
-            contest.filename_with_extension = Some(filename.clone());
-            contest.filename_without_extension = Some("code".to_string());
+        if let Ok(mut context) = CONTEXT.lock() {
+            context.update(problem_path_str);
+            context.filename_with_extension = Some(filename.clone());
+            context.filename_without_extension = Some("code".to_string());
         }
         let mut content = String::new();
         if language_config.template_path != "" {
-            let raw_content = match fs::read_to_string(language_config.template_path).await {
+            let raw_content = match fs::read_to_string(&language_config.template_path).await {
                 Ok(content) => content,
                 Err(info) => {
                     log::info!(
@@ -104,7 +130,7 @@ impl GenerateCommand {
                 }
             };
         }
-        match fs::write(filename.clone(), content).await {
+        match fs::write(problem_path.join(&filename), content).await {
             Ok(_) => {
                 return Ok(format!("Generate {} success", filename));
             }
@@ -0,0 +1,158 @@
+use std::path::Path;
+use std::time::Duration;
+use tokio::fs;
+
+use prettytable::{row, table};
+
+use super::model::HackArgs;
+use crate::database::CONFIG_DB;
+use crate::model::{HackInfo, Platform, Verdict};
+use crate::platform::error::OnlineJudgeError;
+use crate::platform::OnlineJudge;
+use crate::utility::backoff::Backoff;
+use crate::utility::Utility;
+
+/// Give up polling if the hack is still not judged after this many attempts.
+const MAX_POLL_ATTEMPTS: u32 = 30;
+
+pub struct HackCommand {}
+
+impl HackCommand {
+    pub async fn handle(args: HackArgs) -> Result<String, OnlineJudgeError> {
+        let contest_identifier = match args.contest_identifier {
+            Some(contest_identifier) => contest_identifier,
+            None => match Utility::get_identifiers_from_current_dir() {
+                Ok((Platform::Codeforces, contest_identifier, _)) => contest_identifier,
+                Ok((platform, _, _)) => {
+                    return Err(format!("Hacks are not supported on {}", platform).into());
+                }
+                Err(info) => {
+                    return Err(format!(
+                        "Contest is required outside of problem directory, {}",
+                        info
+                    )
+                    .into());
+                }
+            },
+        };
+        // Command line of the generator is posted as the test.
+        let input = match (args.input, args.args) {
+            (Some(input), _) => match fs::read_to_string(&input).await {
+                Ok(input) => input,
+                Err(_) => {
+                    return Err(format!("Cannot read input {}", input).into());
+                }
+            },
+            (None, Some(command_line)) => command_line,
+            (None, None) => {
+                return Err(String::from("Input or generator is required").into());
+            }
+        };
+        let generator = match args.generator {
+            Some(generator) => match Self::get_generator(&generator).await {
+                Ok(generator) => Some(generator),
+                Err(info) => {
+                    return Err(info.into());
+                }
+            },
+            None => None,
+        };
+        let account_info = match CONFIG_DB.get_default_account(Platform::Codeforces) {
+            Ok(account_info) => account_info,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let mut oj = match OnlineJudge::new(account_info, Platform::Codeforces) {
+            Ok(oj) => oj,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        // Remember existing hacks, so that the new one can be found after posting.
+        let hack_ids = match oj.get_hacks(&contest_identifier).await {
+            Ok(hacks) => hacks.into_iter().map(|x| x.hack_id).collect::<Vec<_>>(),
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        let generator = generator
+            .as_ref()
+            .map(|(code, lang_id)| (code.as_str(), lang_id.as_str()));
+        if let Err(info) = oj
+            .hack(&contest_identifier, &args.submission_id, &input, generator)
+            .await
+        {
+            return Err(info);
+        }
+        println!("Hack submission {} success.", args.submission_id);
+        let mut backoff = Backoff::new(Duration::from_secs(1), Duration::from_secs(8));
+        while backoff.attempts() < MAX_POLL_ATTEMPTS {
+            backoff.wait().await;
+            let hacks = match oj.get_hacks(&contest_identifier).await {
+                Ok(hacks) => hacks,
+                Err(info) => {
+                    log::warn!("Failed to get hacks, {}", info);
+                    continue;
+                }
+            };
+            let hack = hacks.into_iter().find(|x| {
+                !hack_ids.contains(&x.hack_id) && x.hacker.eq_ignore_ascii_case(&oj.username)
+            });
+            if let Some(hack) = hack {
+                if hack.verdict != Verdict::Waiting {
+                    Self::show_result(&hack);
+                    return Ok(String::from("Hack command success"));
+                }
+                log::info!("Hack {} is {}", hack.hack_id, hack.verdict_info);
+            }
+        }
+        return Err(format!(
+            "Cannot get hack verdict, see https://codeforces.com/contest/{}/hacks",
+            contest_identifier
+        )
+        .into());
+    }
+
+    /// Read generator source, and find its submit language by the suffix.
+    async fn get_generator(generator: &str) -> Result<(String, String), String> {
+        let code = match fs::read_to_string(generator).await {
+            Ok(code) => code,
+            Err(_) => {
+                return Err(format!("Cannot read generator {}", generator));
+            }
+        };
+        let suffix = match Path::new(generator).extension() {
+            Some(suffix) => suffix.to_string_lossy().to_string(),
+            None => {
+                return Err(format!("Cannot get suffix of generator {}", generator));
+            }
+        };
+        let language_configs = match CONFIG_DB
+            .get_language_config_by_suffix_and_platform(&suffix, Platform::Codeforces)
+        {
+            Ok(language_configs) => language_configs,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        return match language_configs.first() {
+            Some(language_config) => Ok((code, language_config.submit_id.clone())),
+            None => Err(format!("No language config for suffix {}", suffix)),
+        };
+    }
+
+    fn show_result(hack: &HackInfo) {
+        let mut table = table!(
+            ["hack id", hack.hack_id],
+            ["defender", hack.defender],
+            ["problem", hack.problem_identifier]
+        );
+        match hack.verdict_info.as_str() {
+            "Successful hacking attempt" => table.add_row(row!["verdict", bFg->hack.verdict_info]),
+            _ => table.add_row(row!["verdict", bFr->hack.verdict_info]),
+        };
+        table.set_format(*prettytable::format::consts::FORMAT_CLEAN);
+        table.printstd();
+    }
+}
@@ -0,0 +1,74 @@
+use std::env::current_dir;
+
+use prettytable::table;
+
+use super::model::InfoArgs;
+use crate::utility::Utility;
+
+pub struct InfoCommand {}
+
+impl InfoCommand {
+    pub async fn handle(_: InfoArgs) -> Result<String, String> {
+        let (platform, _, problem_identifier) = match Utility::get_identifiers_from_current_dir() {
+            Ok(identifiers) => identifiers,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        let current_dir = match current_dir() {
+            Ok(current_dir) => current_dir,
+            Err(_) => {
+                return Err("Cannot get current path".to_string());
+            }
+        };
+        if !current_dir.join("metadata.json").exists() {
+            println!("Metadata not found, run `ace parse` to download it.");
+            return Err("Metadata not found".to_string());
+        }
+        let metadata = match Utility::load_problem_metadata(&current_dir) {
+            Ok(metadata) => metadata,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        let unknown = || String::from("unknown");
+        let yes_or_no = |value: bool| match value {
+            true => String::from("yes"),
+            false => String::from("no"),
+        };
+        let mut table = table!(
+            [
+                "problem",
+                format!("{} {}", platform, problem_identifier.to_uppercase())
+            ],
+            [
+                "time limit",
+                metadata
+                    .time_limit
+                    .map_or_else(unknown, |x| format!("{} ms", x))
+            ],
+            [
+                "memory limit",
+                metadata
+                    .memory_limit
+                    .map_or_else(unknown, |x| format!("{} MB", x))
+            ],
+            ["interactive", yes_or_no(metadata.interactive)],
+            ["special judge", yes_or_no(metadata.special_judge)],
+            [
+                "error tolerance",
+                metadata
+                    .error_tolerance
+                    .map_or_else(|| String::from("none"), |x| x.to_string())
+            ],
+            [
+                "difficulty",
+                metadata.difficulty.map_or_else(unknown, |x| x.to_string())
+            ],
+            ["tags", metadata.tags.join(", ")]
+        );
+        table.set_format(*prettytable::format::consts::FORMAT_CLEAN);
+        table.printstd();
+        return Ok(String::from("Info command success"));
+    }
+}
@@ -1,3 +1,4 @@
+use colored::Colorize;
 use inquire::MultiSelect;
 use inquire::Select;
 use prettytable::row;
@@ -5,12 +6,14 @@ use prettytable::Table;
 use strum::IntoEnumIterator;
 
 use super::model::LanguageArgs;
+use crate::model::Platform;
+use crate::platform::OnlineJudge;
 use crate::utility::language::LanguageUtility;
 use crate::{command::model::LanguageOptions, constants::ProgramLanguage, database::CONFIG_DB};
 pub struct LanguageCommand {}
 
 impl LanguageCommand {
-    pub fn handle(args: LanguageArgs) -> Result<String, String> {
+    pub async fn handle(args: LanguageArgs) -> Result<String, String> {
         match args.options {
             LanguageOptions::List => {
                 let mut table = Table::new();
@@ -100,6 +103,62 @@ impl LanguageCommand {
                     }
                 }
             }
+            LanguageOptions::Refresh => Self::refresh_languages().await,
         }
     }
+
+    /// Refresh languages of platforms with default account, and warn about
+    /// language configs whose submit id is not available anymore.
+    async fn refresh_languages() -> Result<String, String> {
+        for platform in Platform::iter() {
+            let account_info = match CONFIG_DB.get_default_account(platform) {
+                Ok(account_info) => account_info,
+                Err(_) => {
+                    println!("Skip {}, no default account.", platform);
+                    continue;
+                }
+            };
+            let mut oj = match OnlineJudge::new(account_info, platform) {
+                Ok(oj) => oj,
+                Err(info) => {
+                    return Err(format!(
+                        "Refresh languages of {} failed, {}",
+                        platform, info
+                    ));
+                }
+            };
+            let languages = match oj.refresh_platform_languages().await {
+                Ok(languages) => languages,
+                Err(info) => {
+                    return Err(format!(
+                        "Refresh languages of {} failed, {}",
+                        platform, info
+                    ));
+                }
+            };
+            println!(
+                "Refresh {} languages of {} success.",
+                languages.len(),
+                platform.to_string().bright_blue()
+            );
+            let outdated_configs =
+                match LanguageUtility::get_outdated_language_configs(platform, &languages) {
+                    Ok(outdated_configs) => outdated_configs,
+                    Err(info) => {
+                        return Err(info);
+                    }
+                };
+            for config in outdated_configs {
+                println!(
+                    "{}",
+                    format!(
+                        "Language config {} submits with {} ({}), which is not available on {} anymore.",
+                        config.alias, config.submit_description, config.submit_id, platform
+                    )
+                    .yellow()
+                );
+            }
+        }
+        return Ok(String::from("Refresh languages success"));
+    }
 }
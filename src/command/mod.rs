@@ -1,22 +1,50 @@
 mod account;
+mod cache;
 mod config;
+mod contests;
+mod fetch_submission;
 mod generate;
+mod hack;
+mod info;
 mod language;
 pub mod model;
 mod parse;
+mod race;
+mod remote_run;
 mod setup;
+mod standings;
+mod statement;
+mod status;
 mod submit;
 mod test;
+mod upsolve;
+mod virtual_contest;
+mod watch;
 use self::account::AccountCommand;
+use self::cache::CacheCommand;
 use self::config::ConfigCommand;
+use self::contests::ContestsCommand;
+use self::fetch_submission::FetchSubmissionCommand;
 use self::generate::GenerateCommand;
+use self::hack::HackCommand;
+use self::info::InfoCommand;
 use self::language::LanguageCommand;
 use self::model::Commands;
 use self::parse::ParseCommand;
+use self::race::RaceCommand;
+use self::remote_run::RemoteRunCommand;
 use self::setup::SetupCommand;
+use self::standings::StandingsCommand;
+use self::statement::StatementCommand;
+use self::status::StatusCommand;
 use self::submit::SubmitCommand;
 use self::test::TestCommand;
+use self::upsolve::UpsolveCommand;
+use self::virtual_contest::VirtualContestCommand;
+use self::watch::WatchCommand;
 use crate::context::CONTEXT;
+use crate::platform::error::OnlineJudgeError;
+use crate::utility::http_trace::HttpTrace;
 use clap::Parser;
 use colored::Colorize;
 use log::LevelFilter;
@@ -28,6 +56,9 @@ pub struct Cli {
     pub command: Commands,
     #[arg(short, long)]
     pub verbose: bool,
+    /// Log requests and save responses into ~/.ace/trace, for reporting broken parsers
+    #[arg(long, global = true)]
+    pub trace_http: bool,
 }
 impl Cli {
     pub async fn run() -> Result<(), String> {
@@ -65,6 +96,12 @@ impl Cli {
                 .write_style(env_logger::WriteStyle::Auto)
                 .init();
         }
+        if cli.trace_http {
+            match HttpTrace::enable() {
+                Ok(trace_dir) => println!("Trace HTTP requests into {}", trace_dir.display()),
+                Err(info) => println!("{}", info.yellow()),
+            }
+        }
         match CONTEXT.lock() {
             Ok(mut context) => {
                 context.current_directory = match std::env::current_dir() {
@@ -78,21 +115,52 @@ impl Cli {
             Err(_) => {}
         }
         let res = match cli.command {
-            Commands::Account(args) => AccountCommand::handle(args),
-            Commands::Config(args) => ConfigCommand::handle(args),
-            Commands::Lang(args) => LanguageCommand::handle(args),
+            Commands::Account(args) => AccountCommand::handle(args)
+                .await
+                .map_err(OnlineJudgeError::Other),
+            Commands::Config(args) => ConfigCommand::handle(args).map_err(OnlineJudgeError::Other),
+            Commands::Lang(args) => LanguageCommand::handle(args)
+                .await
+                .map_err(OnlineJudgeError::Other),
             Commands::Parse(args) => ParseCommand::handle(args).await,
-            Commands::Gen(args) => GenerateCommand::handle(args).await,
+            Commands::Gen(args) => GenerateCommand::handle(args)
+                .await
+                .map_err(OnlineJudgeError::Other),
             Commands::Submit(args) => SubmitCommand::handle(args).await,
-            Commands::Test(args) => TestCommand::handle(args).await,
-            Commands::Setup(args) => SetupCommand::handle(args),
+            Commands::Test(args) => TestCommand::handle(args)
+                .await
+                .map_err(OnlineJudgeError::Other),
+            Commands::Race(args) => RaceCommand::handle(args).await,
+            Commands::Statement(args) => StatementCommand::handle(args)
+                .await
+                .map_err(OnlineJudgeError::Other),
+            Commands::Info(args) => InfoCommand::handle(args)
+                .await
+                .map_err(OnlineJudgeError::Other),
+            Commands::Status(args) => StatusCommand::handle(args).await,
+            Commands::FetchSubmission(args) => FetchSubmissionCommand::handle(args).await,
+            Commands::Watch(args) => WatchCommand::handle(args).await,
+            Commands::Standings(args) => StandingsCommand::handle(args).await,
+            Commands::Upsolve(args) => UpsolveCommand::handle(args)
+                .await
+                .map_err(OnlineJudgeError::Other),
+            Commands::Contests(args) => ContestsCommand::handle(args).await,
+            Commands::RemoteRun(args) => RemoteRunCommand::handle(args).await,
+            Commands::Virtual(args) => VirtualContestCommand::handle(args).await,
+            Commands::Hack(args) => HackCommand::handle(args).await,
+            Commands::Cache(args) => CacheCommand::handle(args).map_err(OnlineJudgeError::Other),
+            Commands::Setup(args) => SetupCommand::handle(args).map_err(OnlineJudgeError::Other),
         };
         match res {
             Ok(res) => {
                 log::info!("{}", res);
             }
             Err(info) => {
-                log::info!("{}", info.red());
+                // Errors are only shown in verbose mode, but blocked requests need users to act.
+                if let Some(hint) = info.hint() {
+                    println!("{}", format!("{} {}", info, hint).yellow());
+                }
+                log::info!("{}", info.to_string().red());
             }
         }
         return Ok(());
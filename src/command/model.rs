@@ -17,6 +17,32 @@ pub enum Commands {
     Submit(SubmitArgs),
     /// Run the code locally, and compare the output with the answer
     Test(TestArgs),
+    /// Wait for the contest to start, then parse test cases and generate code files
+    Race(RaceArgs),
+    /// Show the problem statement in terminal
+    Statement(StatementArgs),
+    /// Show limits, difficulty and tags of the problem
+    Info(InfoArgs),
+    /// List recent submissions of current account
+    Status(StatusArgs),
+    /// Download source code and verdict of a submission
+    FetchSubmission(FetchSubmissionArgs),
+    /// Watch the verdict of a submission until it's judged
+    Watch(WatchArgs),
+    /// Show the standings of a contest
+    Standings(StandingsArgs),
+    /// List unsolved problems of contests parsed into workspace
+    Upsolve(UpsolveArgs),
+    /// List running and upcoming contests
+    Contests(ContestsArgs),
+    /// Run the code with custom input on the judge of target platform
+    RemoteRun(RemoteRunArgs),
+    /// Participate in an ended contest virtually
+    Virtual(VirtualArgs),
+    /// Hack a submission on Codeforces
+    Hack(HackArgs),
+    /// Manage cached pages of contests and problems
+    Cache(CacheArgs),
     /// Setup the ace
     Setup(SetupArgs),
 }
@@ -33,6 +59,12 @@ pub enum AccountOptions {
     Update,
     /// Remove account
     Delete,
+    /// Import cookies of a logged in browser session
+    ImportCookies {
+        /// Netscape cookies.txt, cookie header is prompted if not set
+        #[arg(short, long)]
+        file: Option<String>,
+    },
 }
 #[derive(Args)]
 pub struct AccountArgs {
@@ -71,6 +103,8 @@ pub enum LanguageOptions {
     Delete,
     /// Set default language
     SetDefault,
+    /// Refresh submit languages from the platforms
+    Refresh,
 }
 
 #[derive(Args)]
@@ -83,6 +117,15 @@ pub struct LanguageArgs {
 pub struct ParseArgs {
     pub platform: String,
     pub contest_identifier: String,
+    /// Fetch pages again instead of using cached ones
+    #[arg(long)]
+    pub no_cache: bool,
+}
+
+#[derive(Args)]
+pub struct RaceArgs {
+    pub platform: String,
+    pub contest_identifier: String,
 }
 
 #[derive(Args)]
@@ -93,6 +136,9 @@ pub struct GenerateArgs {
 #[derive(Args)]
 pub struct SubmitArgs {
     pub filename: Option<String>,
+    /// Seconds to watch the verdict, default to config `poll-timeout` or 300
+    #[arg(short, long)]
+    pub timeout: Option<u64>,
 }
 
 #[derive(Args)]
@@ -102,3 +148,130 @@ pub struct TestArgs {
 
 #[derive(Args)]
 pub struct SetupArgs {}
+
+#[derive(Args)]
+pub struct StatementArgs {}
+
+#[derive(Args)]
+pub struct StatusArgs {
+    /// Detected from current directory if not set
+    pub platform: Option<String>,
+    pub contest_identifier: Option<String>,
+}
+
+#[derive(Args)]
+pub struct FetchSubmissionArgs {
+    pub submission_id: String,
+    /// Detected from current directory if not set
+    #[arg(short, long, requires = "contest_identifier")]
+    pub platform: Option<String>,
+    /// Detected from current directory if not set
+    #[arg(short, long, requires = "platform")]
+    pub contest_identifier: Option<String>,
+}
+
+#[derive(Args)]
+pub struct StandingsArgs {
+    pub platform: String,
+    pub contest_identifier: String,
+    /// Only show current account and friends in config `<platform>-friends`, separated by comma
+    #[arg(short, long)]
+    pub friends: bool,
+}
+
+#[derive(Args)]
+pub struct ContestsArgs {
+    /// All platforms if not set
+    #[arg(short, long)]
+    pub platform: Option<String>,
+    /// Export contests to an iCalendar file
+    #[arg(long)]
+    pub ics: Option<String>,
+}
+
+#[derive(Args)]
+pub struct RemoteRunArgs {
+    pub filename: Option<String>,
+    /// Input file, the first sample input is used if not set
+    #[arg(short, long)]
+    pub input: Option<String>,
+}
+
+#[derive(Args)]
+pub struct WatchArgs {
+    pub submission_id: String,
+    /// Detected from current directory if not set
+    #[arg(short, long, requires = "contest_identifier")]
+    pub platform: Option<String>,
+    /// Detected from current directory if not set
+    #[arg(short, long, requires = "platform")]
+    pub contest_identifier: Option<String>,
+    /// Seconds to watch the verdict, default to config `poll-timeout` or 300
+    #[arg(short, long)]
+    pub timeout: Option<u64>,
+}
+
+#[derive(Subcommand)]
+pub enum VirtualOptions {
+    /// Parse an ended contest and start the timer
+    Start {
+        platform: String,
+        contest_identifier: String,
+    },
+    /// Show elapsed time and results of problems
+    Status,
+    /// End the virtual contest and show the rank in official standings
+    End,
+}
+
+#[derive(Args)]
+pub struct VirtualArgs {
+    #[command(subcommand)]
+    pub options: VirtualOptions,
+}
+
+#[derive(Args)]
+pub struct HackArgs {
+    pub submission_id: String,
+    /// File of the test input
+    #[arg(
+        short,
+        long,
+        required_unless_present = "generator",
+        conflicts_with = "generator"
+    )]
+    pub input: Option<String>,
+    /// Source file of the generator, submit language is found by its suffix
+    #[arg(short, long, requires = "args")]
+    pub generator: Option<String>,
+    /// Command line of the generator, e.g. "gen 100000 7"
+    #[arg(short, long, requires = "generator")]
+    pub args: Option<String>,
+    /// Detected from current directory if not set
+    #[arg(short, long)]
+    pub contest_identifier: Option<String>,
+}
+
+#[derive(Args)]
+pub struct InfoArgs {}
+
+#[derive(Args)]
+pub struct UpsolveArgs {
+    /// All platforms if not set
+    #[arg(short, long)]
+    pub platform: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum CacheOptions {
+    /// Remove all cached pages
+    Clear,
+    /// Show number and size of cached pages
+    Stats,
+}
+
+#[derive(Args)]
+pub struct CacheArgs {
+    #[command(subcommand)]
+    pub options: CacheOptions,
+}
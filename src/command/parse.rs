@@ -1,84 +1,117 @@
-use std::path;
-use tokio::fs;
-
-use colored::Colorize;
+use std::path::{self, Path, PathBuf};
 
 use super::model::ParseArgs;
 use crate::constants::PLATFORM_MAP;
 use crate::database::CONFIG_DB;
+use crate::model::ProblemDetail;
+use crate::platform::error::OnlineJudgeError;
 use crate::platform::OnlineJudge;
+use crate::utility::Utility;
 
 pub struct ParseCommand {}
 
 impl ParseCommand {
-    pub async fn handle(args: ParseArgs) -> Result<String, String> {
+    pub async fn handle(args: ParseArgs) -> Result<String, OnlineJudgeError> {
         let real_platform = match PLATFORM_MAP.get(args.platform.as_str()) {
             Some(platform) => *platform,
             None => {
-                return Err(format!("Platform {} not found", args.platform));
+                return Err(format!("Platform {} not found", args.platform).into());
             }
         };
         let account_info = match CONFIG_DB.get_default_account(real_platform) {
             Ok(account_info) => account_info,
             Err(info) => {
-                return Err(info);
+                return Err(info.into());
             }
         };
-        let mut oj = OnlineJudge::new(account_info, real_platform);
-        let contest_test_cases = match oj.get_contest_test_cases(&args.contest_identifier).await {
-            Ok(test_cases) => test_cases,
-            Err(info) => return Err(info),
+        let mut oj = match OnlineJudge::new(account_info, real_platform) {
+            Ok(oj) => oj,
+            Err(info) => {
+                return Err(info.into());
+            }
         };
-        let platform_str = real_platform.to_string();
+        oj.http_client.set_use_page_cache(!args.no_cache);
+        if let Err(info) = Self::parse_contest(&mut oj, &args.contest_identifier).await {
+            return Err(info);
+        }
+        return Ok(String::from("Parse command success"));
+    }
+
+    /// Fetch test cases, statements and metadata of a contest, and save each problem into
+    /// workspace as soon as it arrives.
+    /// # Returns
+    /// * `Ok(Vec<PathBuf>)` - Directories of the problems in sorted order.
+    pub async fn parse_contest(
+        oj: &mut OnlineJudge,
+        contest_identifier: &str,
+    ) -> Result<Vec<PathBuf>, OnlineJudgeError> {
         let workspace = match CONFIG_DB.get_config("workspace") {
             Ok(workspace) => workspace,
             Err(info) => {
-                return Err(info);
+                return Err(info.into());
             }
         };
         let contest_path = path::Path::new(workspace.as_str())
-            .join(platform_str)
-            .join(args.contest_identifier.to_lowercase());
-        match fs::create_dir_all(contest_path.clone()).await {
-            Ok(_) => {}
-            Err(_) => {
-                return Err(String::from("Create contest directory failed"));
-            }
+            .join(oj.platform.to_string())
+            .join(contest_identifier.to_lowercase());
+        if std::fs::create_dir_all(&contest_path).is_err() {
+            return Err(String::from("Create contest directory failed").into());
         }
-        for (problem_identifier, test_cases) in contest_test_cases {
-            let vec = problem_identifier.split("_").collect::<Vec<_>>();
-            if vec.len() != 2 {
-                return Err(String::from("Invalid problem identifier."));
-            }
-            let contest_problem_identifier = vec[1];
-            let problem_path = contest_path.join(contest_problem_identifier.to_lowercase());
-            match fs::create_dir_all(problem_path.clone()).await {
-                Ok(_) => {}
-                Err(_) => {
-                    return Err(String::from("Create problem directory failed"));
-                }
-            }
-            for (index, test_case) in test_cases.iter().enumerate() {
-                let input_path = problem_path.clone().join(format!("{:03}i.txt", index + 1));
-                let output_path = problem_path.clone().join(format!("{:03}o.txt", index + 1));
-                match fs::write(input_path, test_case.input.as_bytes()).await {
-                    Ok(_) => {}
-                    Err(_) => {
-                        return Err(String::from("Write input file failed"));
-                    }
-                }
-                match fs::write(output_path, test_case.output.as_bytes()).await {
-                    Ok(_) => {}
-                    Err(_) => {
-                        return Err(String::from("Write output file failed"));
+        let mut problem_paths = Vec::new();
+        let result = oj
+            .get_contest_test_cases(contest_identifier, |problem_identifier, problem_detail| {
+                match Self::save_problem(&contest_path, problem_identifier, &problem_detail) {
+                    Ok(problem_path) => {
+                        problem_paths.push(problem_path);
+                        Ok(())
                     }
+                    Err(info) => Err(info),
                 }
+            })
+            .await;
+        if let Err(info) = result {
+            return Err(info);
+        }
+        problem_paths.sort();
+        return Ok(problem_paths);
+    }
+
+    /// Save test cases, statement and metadata of a problem into the contest directory.
+    /// # Returns
+    /// * `Ok(PathBuf)` - Directory of the problem.
+    fn save_problem(
+        contest_path: &Path,
+        problem_identifier: &str,
+        problem_detail: &ProblemDetail,
+    ) -> Result<PathBuf, String> {
+        let vec = problem_identifier.split("_").collect::<Vec<_>>();
+        if vec.len() != 2 {
+            return Err(String::from("Invalid problem identifier."));
+        }
+        let contest_problem_identifier = vec[1];
+        let problem_path = contest_path.join(contest_problem_identifier.to_lowercase());
+        if std::fs::create_dir_all(&problem_path).is_err() {
+            return Err(String::from("Create problem directory failed"));
+        }
+        for (index, test_case) in problem_detail.test_cases.iter().enumerate() {
+            let input_path = problem_path.join(format!("{:03}i.txt", index + 1));
+            let output_path = problem_path.join(format!("{:03}o.txt", index + 1));
+            if std::fs::write(input_path, test_case.input.as_bytes()).is_err() {
+                return Err(String::from("Write input file failed"));
+            }
+            if std::fs::write(output_path, test_case.output.as_bytes()).is_err() {
+                return Err(String::from("Write output file failed"));
             }
-            println!(
-                "Save test case for {} success.",
-                problem_identifier.bright_blue()
-            );
         }
-        return Ok(String::from("Parse command success"));
+        if !problem_detail.statement.is_empty() {
+            let statement_path = problem_path.join("statement.md");
+            if std::fs::write(statement_path, problem_detail.statement.as_bytes()).is_err() {
+                return Err(String::from("Write statement file failed"));
+            }
+        }
+        if let Err(info) = Utility::save_problem_metadata(&problem_path, &problem_detail.metadata) {
+            return Err(format!("Write metadata file failed, {}", info));
+        }
+        return Ok(problem_path);
     }
 }
@@ -0,0 +1,150 @@
+use std::io::Write;
+use std::time::Duration;
+
+use colored::Colorize;
+
+use super::generate::GenerateCommand;
+use super::model::RaceArgs;
+use super::parse::ParseCommand;
+use crate::constants::PLATFORM_MAP;
+use crate::database::CONFIG_DB;
+use crate::model::{Contest, ContestStatus};
+use crate::platform::error::OnlineJudgeError;
+use crate::platform::OnlineJudge;
+use crate::utility::backoff::Backoff;
+
+/// Give up polling if the problems are still not available after this many attempts.
+const MAX_POLL_ATTEMPTS: u32 = 20;
+
+pub struct RaceCommand {}
+
+impl RaceCommand {
+    pub async fn handle(args: RaceArgs) -> Result<String, OnlineJudgeError> {
+        let real_platform = match PLATFORM_MAP.get(args.platform.as_str()) {
+            Some(platform) => *platform,
+            None => {
+                return Err(format!("Platform {} not found", args.platform).into());
+            }
+        };
+        let account_info = match CONFIG_DB.get_default_account(real_platform) {
+            Ok(account_info) => account_info,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        // Resolve language config before the contest starts, so that no prompt blocks us later.
+        let language_config = match GenerateCommand::get_language_config(None, real_platform) {
+            Ok(language_config) => Some(language_config),
+            Err(info) => {
+                println!("{}, code files will not be generated.", info.yellow());
+                None
+            }
+        };
+        let mut oj = match OnlineJudge::new(account_info, real_platform) {
+            Ok(oj) => oj,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        // Pages are polled until problems are published, cached pages may be outdated.
+        oj.http_client.set_use_page_cache(false);
+        let contest = match oj.get_contest(&args.contest_identifier).await {
+            Ok(contest) => contest,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        if contest.status == ContestStatus::Ended {
+            println!("Contest {} has ended.", contest.title.trim().bright_blue());
+        } else if contest.status == ContestStatus::NotStarted {
+            Self::countdown(&contest).await;
+        }
+
+        let mut backoff = Backoff::new(Duration::from_millis(500), Duration::from_secs(8));
+        let problem_paths = loop {
+            match ParseCommand::parse_contest(&mut oj, &args.contest_identifier).await {
+                Ok(problem_paths) => break problem_paths,
+                Err(info) => {
+                    if backoff.attempts() >= MAX_POLL_ATTEMPTS {
+                        return Err(info);
+                    }
+                    log::info!("Get test cases failed: {}, retry later.", info);
+                    backoff.wait().await;
+                }
+            }
+        };
+
+        if let Some(language_config) = language_config {
+            for problem_path in problem_paths {
+                let code_path = problem_path.join(format!("code.{}", language_config.suffix));
+                if code_path.exists() {
+                    println!("Skip {}, code file exists.", code_path.display());
+                    continue;
+                }
+                match GenerateCommand::generate(&problem_path, &language_config).await {
+                    Ok(_) => {
+                        println!("Generate code for {} success.", problem_path.display());
+                    }
+                    Err(info) => {
+                        println!(
+                            "Generate code for {} failed: {}",
+                            problem_path.display(),
+                            info
+                        );
+                    }
+                }
+            }
+        }
+        return Ok(String::from("Race command success"));
+    }
+
+    /// Show a live countdown until the contest starts.
+    async fn countdown(contest: &Contest) {
+        loop {
+            let remaining = contest.start_time - chrono::Utc::now();
+            if remaining <= chrono::Duration::zero() {
+                break;
+            }
+            print!(
+                "\r{}Contest {} starts in {}",
+                ansi_escapes::EraseLine,
+                contest.title.trim().bright_blue(),
+                Self::format_remaining(remaining).green()
+            );
+            let _ = std::io::stdout().flush();
+            let wait = remaining
+                .to_std()
+                .unwrap_or(Duration::ZERO)
+                .min(Duration::from_secs(1));
+            tokio::time::sleep(wait).await;
+        }
+        println!(
+            "\r{}Contest {} started.",
+            ansi_escapes::EraseLine,
+            contest.title.trim().bright_blue()
+        );
+    }
+
+    fn format_remaining(remaining: chrono::Duration) -> String {
+        let seconds = remaining.num_seconds();
+        let days = seconds / 86400;
+        let time = format!(
+            "{:02}:{:02}:{:02}",
+            seconds % 86400 / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        );
+        if days > 0 {
+            return format!("{}d {}", days, time);
+        }
+        return time;
+    }
+}
+
+#[test]
+fn test_format_remaining() {
+    let remaining = chrono::Duration::seconds(86400 + 3600 * 2 + 60 * 3 + 4);
+    assert_eq!(RaceCommand::format_remaining(remaining), "1d 02:03:04");
+    let remaining = chrono::Duration::seconds(59);
+    assert_eq!(RaceCommand::format_remaining(remaining), "00:00:59");
+}
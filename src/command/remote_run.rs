@@ -0,0 +1,145 @@
+use std::env::current_dir;
+use std::time::Duration;
+use tokio::fs;
+
+use colored::Colorize;
+
+use super::model::RemoteRunArgs;
+use super::submit::SubmitCommand;
+use crate::database::CONFIG_DB;
+use crate::model::{CustomTestResult, Verdict};
+use crate::platform::error::OnlineJudgeError;
+use crate::platform::OnlineJudge;
+use crate::utility::backoff::Backoff;
+
+/// Give up polling if the result is still not available after this many attempts.
+const MAX_POLL_ATTEMPTS: u32 = 30;
+/// Give up polling if requests failed this many times in a row.
+const MAX_CONSECUTIVE_ERRORS: u32 = 5;
+
+pub struct RemoteRunCommand {}
+
+impl RemoteRunCommand {
+    pub async fn handle(args: RemoteRunArgs) -> Result<String, OnlineJudgeError> {
+        let current_dir = match current_dir() {
+            Ok(current_dir) => current_dir,
+            Err(_) => {
+                return Err("Cannot get current path".to_string().into());
+            }
+        };
+        let current_dir_str = match current_dir.to_str() {
+            Some(current_dir_str) => current_dir_str,
+            None => {
+                return Err("Can't get current path".to_string().into());
+            }
+        };
+        let filename = match SubmitCommand::select_source_file(args.filename, current_dir_str) {
+            Ok(filename) => filename,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let submit_info = match current_dir.join(filename.clone()).to_str() {
+            Some(file_path) => match SubmitCommand::get_submit_info(&filename, file_path).await {
+                Ok(submit_info) => submit_info,
+                Err(info) => {
+                    return Err(info.into());
+                }
+            },
+            None => {
+                return Err("Can't get current path".to_string().into());
+            }
+        };
+        let input_path = match args.input {
+            Some(input) => current_dir.join(input),
+            None => current_dir.join("001i.txt"),
+        };
+        let input = match fs::read_to_string(&input_path).await {
+            Ok(input) => input,
+            Err(_) => {
+                return Err(format!("Cannot read input {}", input_path.display()).into());
+            }
+        };
+        let account_info = match CONFIG_DB.get_default_account(submit_info.platform) {
+            Ok(account_info) => account_info,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let mut oj = match OnlineJudge::new(account_info, submit_info.platform) {
+            Ok(oj) => oj,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let (custom_test_id, csrf_token) = match oj
+            .custom_test(
+                &submit_info.contest_identifier,
+                &submit_info.code,
+                &submit_info.language_id,
+                &input,
+            )
+            .await
+        {
+            Ok(custom_test) => custom_test,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        println!(
+            "Running {} on {}...",
+            filename.bright_blue(),
+            submit_info.platform
+        );
+        let mut backoff = Backoff::new(Duration::from_secs(1), Duration::from_secs(5));
+        let mut errors = 0;
+        while backoff.attempts() < MAX_POLL_ATTEMPTS {
+            backoff.wait().await;
+            let result = match oj
+                .retrive_custom_test_result(
+                    &submit_info.contest_identifier,
+                    &custom_test_id,
+                    &csrf_token,
+                )
+                .await
+            {
+                Ok(result) => {
+                    errors = 0;
+                    result
+                }
+                Err(info) => {
+                    errors += 1;
+                    log::warn!("Failed to get custom test result, {}", info);
+                    // Blocked requests keep their type to show the hint.
+                    if errors >= MAX_CONSECUTIVE_ERRORS {
+                        return Err(info);
+                    }
+                    continue;
+                }
+            };
+            if result.verdict != Verdict::Waiting {
+                Self::show_result(&result);
+                return Ok(String::from("Remote run command success"));
+            }
+        }
+        return Err("Cannot get custom test result".to_string().into());
+    }
+
+    fn show_result(result: &CustomTestResult) {
+        let exit_code = match result.exit_code {
+            Some(0) => "0".green(),
+            Some(exit_code) => exit_code.to_string().red(),
+            None => "unknown".yellow(),
+        };
+        println!(
+            "exit code: {}, time: {}, memory: {}",
+            exit_code, result.execute_time, result.execute_memory
+        );
+        println!("{}", "stdout:".bright_blue());
+        print!("{}", result.stdout);
+        if !result.stderr.is_empty() {
+            println!("{}", "stderr:".bright_blue());
+            print!("{}", result.stderr);
+        }
+    }
+}
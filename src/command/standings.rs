@@ -0,0 +1,125 @@
+use prettytable::{Cell, Row, Table};
+
+use super::model::StandingsArgs;
+use crate::constants::PLATFORM_MAP;
+use crate::database::CONFIG_DB;
+use crate::model::{Platform, Standings};
+use crate::platform::error::OnlineJudgeError;
+use crate::platform::OnlineJudge;
+
+/// Count of top rows shown without `--friends`.
+const TOP_ROWS: usize = 20;
+
+pub struct StandingsCommand {}
+
+impl StandingsCommand {
+    pub async fn handle(args: StandingsArgs) -> Result<String, OnlineJudgeError> {
+        let platform = match PLATFORM_MAP.get(args.platform.as_str()) {
+            Some(platform) => *platform,
+            None => {
+                return Err(format!("Platform {} not found", args.platform).into());
+            }
+        };
+        let account_info = match CONFIG_DB.get_default_account(platform) {
+            Ok(account_info) => account_info,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let username = account_info.username.clone();
+        let friends = Self::get_friends(platform);
+        let mut oj = match OnlineJudge::new(account_info, platform) {
+            Ok(oj) => oj,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let standings = match args.friends {
+            true => {
+                let mut handles = vec![username.clone()];
+                handles.extend(friends.iter().cloned());
+                oj.get_standings(&args.contest_identifier, &handles).await
+            }
+            false => match oj.get_standings(&args.contest_identifier, &[]).await {
+                Ok(mut standings) => {
+                    standings.rows.truncate(TOP_ROWS);
+                    let contains_self = standings
+                        .rows
+                        .iter()
+                        .any(|x| x.handle.eq_ignore_ascii_case(&username));
+                    if !contains_self {
+                        // Append row of current account after top rows.
+                        match oj
+                            .get_standings(&args.contest_identifier, &[username.clone()])
+                            .await
+                        {
+                            Ok(own) => standings.rows.extend(own.rows),
+                            Err(info) => {
+                                log::warn!("Get standings of {} failed: {}", username, info);
+                            }
+                        }
+                    }
+                    Ok(standings)
+                }
+                Err(info) => Err(info),
+            },
+        };
+        let standings = match standings {
+            Ok(standings) => standings,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        Self::show_standings(&standings, &username, &friends);
+        return Ok(String::from("Standings command success"));
+    }
+
+    /// Friends are stored in config `<platform>-friends`, separated by comma.
+    fn get_friends(platform: Platform) -> Vec<String> {
+        let key = format!("{}-friends", platform.to_string().to_lowercase());
+        return match CONFIG_DB.get_config(&key) {
+            Ok(value) => value
+                .split(',')
+                .map(|x| x.trim().to_string())
+                .filter(|x| !x.is_empty())
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+    }
+
+    fn show_standings(standings: &Standings, username: &str, friends: &[String]) {
+        let mut table = Table::new();
+        let mut header = vec![
+            Cell::new("rank"),
+            Cell::new("handle"),
+            Cell::new("score"),
+            Cell::new("penalty"),
+        ];
+        header.extend(standings.problems.iter().map(|x| Cell::new(x)));
+        table.add_row(Row::new(header));
+        for row in &standings.rows {
+            let style = if row.handle.eq_ignore_ascii_case(username) {
+                "bFg"
+            } else if friends.iter().any(|x| x.eq_ignore_ascii_case(&row.handle)) {
+                "Fy"
+            } else {
+                ""
+            };
+            let mut cells = vec![
+                Cell::new(&row.rank.to_string()).style_spec(style),
+                Cell::new(&row.handle).style_spec(style),
+                Cell::new(&row.score).style_spec(style),
+                Cell::new(&row.penalty).style_spec(style),
+            ];
+            cells.extend(row.problem_results.iter().map(|x| {
+                let result_style = match x.starts_with('-') {
+                    true => "Fr",
+                    false => "Fg",
+                };
+                Cell::new(x).style_spec(result_style)
+            }));
+            table.add_row(Row::new(cells));
+        }
+        table.printstd();
+    }
+}
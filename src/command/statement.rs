@@ -0,0 +1,46 @@
+use std::env::current_dir;
+use tokio::fs;
+
+use super::model::StatementArgs;
+use crate::database::CONFIG_DB;
+use crate::utility::markdown::Markdown;
+use crate::utility::Utility;
+
+pub struct StatementCommand {}
+
+impl StatementCommand {
+    pub async fn handle(_: StatementArgs) -> Result<String, String> {
+        let current_dir = match current_dir() {
+            Ok(current_dir) => current_dir,
+            Err(_) => {
+                return Err("Cannot get current path".to_string());
+            }
+        };
+        let current_dir_str = match current_dir.to_str() {
+            Some(current_dir_str) => current_dir_str,
+            None => {
+                return Err("Can't get current path".to_string());
+            }
+        };
+        let workspace = match CONFIG_DB.get_config("workspace") {
+            Ok(workspace) => workspace,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        if let Err(info) =
+            Utility::get_identifiers_from_currrent_location(current_dir_str, &workspace)
+        {
+            return Err(info);
+        }
+        let statement = match fs::read_to_string(current_dir.join("statement.md")).await {
+            Ok(statement) => statement,
+            Err(_) => {
+                println!("Statement not found, run `ace parse` to download it.");
+                return Err("Statement not found".to_string());
+            }
+        };
+        print!("{}", Markdown::render(&statement));
+        return Ok(String::new());
+    }
+}
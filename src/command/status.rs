@@ -0,0 +1,92 @@
+use prettytable::{row, Cell, Row, Table};
+
+use super::model::StatusArgs;
+use crate::constants::PLATFORM_MAP;
+use crate::database::CONFIG_DB;
+use crate::model::{PostSubmissionInfo, Verdict};
+use crate::platform::error::OnlineJudgeError;
+use crate::platform::OnlineJudge;
+use crate::utility::Utility;
+
+pub struct StatusCommand {}
+
+impl StatusCommand {
+    pub async fn handle(args: StatusArgs) -> Result<String, OnlineJudgeError> {
+        let (platform, contest_identifier) = match args.platform {
+            Some(platform) => match PLATFORM_MAP.get(platform.as_str()) {
+                Some(platform) => (*platform, args.contest_identifier),
+                None => {
+                    return Err(format!("Platform {} not found", platform).into());
+                }
+            },
+            None => match Utility::get_identifiers_from_current_dir() {
+                Ok((platform, contest_identifier, _)) => (platform, Some(contest_identifier)),
+                Err(info) => {
+                    return Err(format!(
+                        "Platform is required outside of problem directory, {}",
+                        info
+                    )
+                    .into());
+                }
+            },
+        };
+        let account_info = match CONFIG_DB.get_default_account(platform) {
+            Ok(account_info) => account_info,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let mut oj = match OnlineJudge::new(account_info, platform) {
+            Ok(oj) => oj,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let submissions = match oj.get_submissions(contest_identifier.as_deref()).await {
+            Ok(submissions) => submissions,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        if submissions.is_empty() {
+            println!("No submission found.");
+            return Ok(String::from("Status command success"));
+        }
+        Self::show_submissions(&submissions);
+        return Ok(String::from("Status command success"));
+    }
+
+    fn show_submissions(submissions: &[PostSubmissionInfo]) {
+        let mut table = Table::new();
+        table.add_row(row![
+            "submission id",
+            "submit time",
+            "contest",
+            "problem",
+            "language",
+            "verdict",
+            "execute time",
+            "execute memory"
+        ]);
+        for submission in submissions {
+            let style = match submission.verdict {
+                Verdict::Waiting => "Fy",
+                Verdict::Resulted => match submission.verdict_info.as_str() {
+                    "Accepted" | "AC" => "Fg",
+                    _ => "Fr",
+                },
+            };
+            table.add_row(Row::new(vec![
+                Cell::new(&submission.submission_id),
+                Cell::new(&submission.submit_time),
+                Cell::new(&submission.contest_identifier),
+                Cell::new(&submission.problem_identifier),
+                Cell::new(&submission.language),
+                Cell::new(&submission.verdict_info).style_spec(style),
+                Cell::new(&submission.execute_time),
+                Cell::new(&submission.execute_memory),
+            ]));
+        }
+        table.printstd();
+    }
+}
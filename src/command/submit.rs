@@ -1,15 +1,24 @@
+use colored::Colorize;
 use inquire::Select;
-use prettytable::table;
+use prettytable::{row, table, Cell, Row, Table};
 
 use super::model::SubmitArgs;
+use super::virtual_contest::VirtualContestCommand;
 use crate::{
     database::CONFIG_DB,
-    model::{Platform, PostSubmissionInfo, Verdict},
-    platform::OnlineJudge,
-    utility::Utility,
+    model::{JudgeTestCase, Platform, PostSubmissionInfo, Verdict},
+    platform::{error::OnlineJudgeError, OnlineJudge},
+    utility::{backoff::Backoff, virtual_contest::VirtualContestUtility, Utility},
 };
+use std::time::{Duration, Instant};
 use std::{env::current_dir, vec};
 use tokio::fs;
+
+/// Give up watching the verdict after this many seconds if config `poll-timeout` is not set.
+const DEFAULT_POLL_TIMEOUT_SECS: u64 = 300;
+/// Give up watching the verdict if requests failed this many times in a row.
+const MAX_CONSECUTIVE_ERRORS: u32 = 5;
+
 pub struct SubmitCommand {}
 #[derive(Debug)]
 pub struct PreSubmissionInfo {
@@ -20,7 +29,12 @@ pub struct PreSubmissionInfo {
     pub contest_identifier: String,
 }
 impl SubmitCommand {
-    async fn show_result(submission_info: &PostSubmissionInfo, reties: u32) {
+    /// Show the submission in place of the table shown last time.
+    /// # Arguments
+    /// * `shown_lines` - Lines of the table shown last time, 0 if nothing is shown.
+    /// # Returns
+    /// * Lines of the table shown this time.
+    async fn show_result(submission_info: &PostSubmissionInfo, shown_lines: usize) -> usize {
         let mut table = match submission_info.verdict {
             Verdict::Waiting => table!(
                 ["submission id", submission_info.submission_id],
@@ -39,18 +53,52 @@ impl SubmitCommand {
                 ["execute memory", submission_info.execute_memory]
             ),
         };
+        if submission_info.verdict != Verdict::Waiting {
+            if let Some(failed_test) = &submission_info.failed_test {
+                table.add_row(row!["failed on test", b -> failed_test]);
+            }
+        }
         table.set_format(*prettytable::format::consts::FORMAT_CLEAN);
-        let output = table.to_string();
-        let lines = output.lines().count() + 1;
-        if reties > 0 {
-            print!("{}", ansi_escapes::EraseLines(lines as u16));
+        // The table may have more rows than the last one, such as the failed test.
+        if shown_lines > 0 {
+            print!("{}", ansi_escapes::EraseLines(shown_lines as u16 + 1));
+        }
+        table.printstd();
+        if submission_info.verdict != Verdict::Waiting {
+            Self::show_judge_test_cases(&submission_info.judge_test_cases);
+        }
+        return table.to_string().lines().count();
+    }
+    fn show_judge_test_cases(judge_test_cases: &[JudgeTestCase]) {
+        if judge_test_cases.is_empty() {
+            return;
+        }
+        let mut table = Table::new();
+        table.add_row(row![
+            "test case",
+            "verdict",
+            "execute time",
+            "execute memory"
+        ]);
+        for judge_test_case in judge_test_cases {
+            let style = match judge_test_case.verdict_info.as_str() {
+                "AC" => "Fg",
+                _ => "Fr",
+            };
+            table.add_row(Row::new(vec![
+                Cell::new(&judge_test_case.name),
+                Cell::new(&judge_test_case.verdict_info).style_spec(style),
+                Cell::new(&judge_test_case.execute_time),
+                Cell::new(&judge_test_case.execute_memory),
+            ]));
         }
         table.printstd();
     }
     async fn submit(
         mut oj: OnlineJudge,
         submit_info: &PreSubmissionInfo,
-    ) -> Result<String, String> {
+        timeout: Duration,
+    ) -> Result<String, OnlineJudgeError> {
         let submission_id = match oj
             .submit(
                 &submit_info.problem_identifier,
@@ -64,56 +112,127 @@ impl SubmitCommand {
                 return Err(info);
             }
         };
-        let mut retries: u32 = 0;
-        while let Ok(post_submission_info) = oj
-            .retrive_result(&submit_info.problem_identifier, &submission_id)
-            .await
-        {
-            Self::show_result(&post_submission_info, retries).await;
-            if post_submission_info.verdict != Verdict::Waiting {
-                return Ok("Submit success".to_string());
-            } else if retries > 100 {
-                break;
+        let submit_time = chrono::Utc::now().timestamp();
+        let result = Self::watch_result(
+            &mut oj,
+            &submit_info.problem_identifier,
+            &submission_id,
+            timeout,
+        )
+        .await;
+        let (verdict_info, judged) = match &result {
+            Ok(post_submission_info) => (post_submission_info.verdict_info.as_str(), true),
+            Err(_) => ("", false),
+        };
+        match VirtualContestUtility::record_submission(
+            submit_info.platform,
+            &submit_info.problem_identifier,
+            &submission_id,
+            submit_time,
+            verdict_info,
+            judged,
+        ) {
+            Ok(Some(virtual_contest)) => {
+                VirtualContestCommand::show_progress(&virtual_contest);
+            }
+            Ok(None) => {}
+            Err(info) => {
+                log::warn!("Record submission of virtual contest failed, {}", info);
+            }
+        }
+        return match result {
+            Ok(_) => Ok("Submit success".to_string()),
+            Err(info) => Err(info),
+        };
+    }
+    /// Poll the verdict with backoff until it's judged, requests failed too many times
+    /// in a row or `timeout` is reached.
+    pub async fn watch_result(
+        oj: &mut OnlineJudge,
+        full_problem_identifier: &str,
+        submission_id: &str,
+        timeout: Duration,
+    ) -> Result<PostSubmissionInfo, OnlineJudgeError> {
+        let deadline = Instant::now() + timeout;
+        let mut backoff = Backoff::new(Duration::from_millis(500), Duration::from_secs(8));
+        let mut shown_lines = 0;
+        let mut errors: u32 = 0;
+        let reason = loop {
+            match oj
+                .retrive_result(full_problem_identifier, submission_id)
+                .await
+            {
+                Ok(post_submission_info) => {
+                    errors = 0;
+                    shown_lines = Self::show_result(&post_submission_info, shown_lines).await;
+                    if post_submission_info.verdict != Verdict::Waiting {
+                        return Ok(post_submission_info);
+                    }
+                }
+                Err(info) => {
+                    errors += 1;
+                    log::warn!("Failed to get submission info, {}", info);
+                    // Blocked requests keep their type to show the hint.
+                    if errors >= MAX_CONSECUTIVE_ERRORS {
+                        break info;
+                    }
+                }
+            }
+            if Instant::now() >= deadline {
+                break format!("Submission is not judged in {} seconds", timeout.as_secs()).into();
             }
-            retries += 1;
+            backoff.wait().await;
+        };
+        let contest_identifier = full_problem_identifier
+            .split('_')
+            .next()
+            .unwrap_or_default();
+        println!(
+            "Run {} to resume watching.",
+            format!(
+                "ace watch {} -p {} -c {}",
+                submission_id,
+                oj.platform.to_string().to_lowercase(),
+                contest_identifier
+            )
+            .bright_blue()
+        );
+        return Err(reason);
+    }
+    /// Use `timeout` seconds if it's set, otherwise config `poll-timeout` or the default one.
+    pub fn get_poll_timeout(timeout: Option<u64>) -> Duration {
+        if let Some(timeout) = timeout {
+            return Duration::from_secs(timeout);
         }
-        return Err("Cannot get submission info".to_string());
+        return match CONFIG_DB.get_config("poll-timeout") {
+            Ok(value) => match value.parse::<u64>() {
+                Ok(timeout) => Duration::from_secs(timeout),
+                Err(_) => {
+                    log::warn!("Invalid config poll-timeout {}", value);
+                    Duration::from_secs(DEFAULT_POLL_TIMEOUT_SECS)
+                }
+            },
+            Err(_) => Duration::from_secs(DEFAULT_POLL_TIMEOUT_SECS),
+        };
     }
-    pub async fn handle(args: SubmitArgs) -> Result<String, String> {
+    pub async fn handle(args: SubmitArgs) -> Result<String, OnlineJudgeError> {
         let current_dir = match current_dir() {
             Ok(current_dir) => current_dir,
             Err(_) => {
-                return Err("Cannot get current path".to_string());
+                return Err("Cannot get current path".to_string().into());
             }
         };
         let current_dir_str = match current_dir.to_str() {
             Some(current_dir_str) => current_dir_str,
             None => {
-                return Err("Can't get current path".to_string());
+                return Err("Can't get current path".to_string().into());
             }
         };
 
-        let filename = match args.filename {
-            Some(filename) => filename,
-            None => {
-                let files = Utility::find_source_code_filename_from_directory(current_dir_str);
-                match files.len() {
-                    0 => {
-                        return Err("No code file found".to_string());
-                    }
-                    1 => files[0].clone(),
-                    _ => {
-                        let filename = match Select::new("Select file to submit: ", files).prompt()
-                        {
-                            Ok(filename) => filename,
-                            Err(info) => {
-                                log::error!("{}", info);
-                                return Err(info.to_string());
-                            }
-                        };
-                        filename
-                    }
-                }
+        let filename = match Self::select_source_file(args.filename, current_dir_str) {
+            Ok(filename) => filename,
+            Err(info) => {
+                return Err(info.into());
             }
         };
         let pre_submission_info = match current_dir.join(filename.clone()).to_str() {
@@ -121,27 +240,56 @@ impl SubmitCommand {
                 Ok(pre_submission_info) => pre_submission_info,
                 Err(info) => {
                     log::error!("{}", info);
-                    return Err(info);
+                    return Err(info.into());
                 }
             },
             None => {
-                return Err("Can't get current path".to_string());
+                return Err("Can't get current path".to_string().into());
             }
         };
         log::info!("{:?}", pre_submission_info);
         let account_info = match CONFIG_DB.get_default_account(pre_submission_info.platform) {
             Ok(account_info) => account_info,
             Err(info) => {
-                return Err(info);
+                return Err(info.into());
             }
         };
 
-        let oj = OnlineJudge::new(account_info, pre_submission_info.platform);
-        return Self::submit(oj, &pre_submission_info).await;
+        let oj = match OnlineJudge::new(account_info, pre_submission_info.platform) {
+            Ok(oj) => oj,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let timeout = Self::get_poll_timeout(args.timeout);
+        return Self::submit(oj, &pre_submission_info, timeout).await;
     }
 }
 impl SubmitCommand {
-    async fn get_submit_info(filename: &str, file_path: &str) -> Result<PreSubmissionInfo, String> {
+    /// Use `filename` if it's set, otherwise find code files in directory and prompt to select one.
+    pub fn select_source_file(filename: Option<String>, directory: &str) -> Result<String, String> {
+        return match filename {
+            Some(filename) => Ok(filename),
+            None => {
+                let files = Utility::find_source_code_filename_from_directory(directory);
+                match files.len() {
+                    0 => Err("No code file found".to_string()),
+                    1 => Ok(files[0].clone()),
+                    _ => match Select::new("Select file to submit: ", files).prompt() {
+                        Ok(filename) => Ok(filename),
+                        Err(info) => {
+                            log::error!("{}", info);
+                            Err(info.to_string())
+                        }
+                    },
+                }
+            }
+        };
+    }
+    pub async fn get_submit_info(
+        filename: &str,
+        file_path: &str,
+    ) -> Result<PreSubmissionInfo, String> {
         let workspace = match CONFIG_DB.get_config("workspace") {
             Ok(workspace) => workspace,
             Err(info) => {
@@ -161,6 +309,7 @@ impl SubmitCommand {
                 return Err(info);
             }
         };
+        Self::check_submit_language(platform, &language_id);
         let code = match fs::read_to_string(file_path).await {
             Ok(code) => code,
             Err(info) => {
@@ -175,6 +324,26 @@ impl SubmitCommand {
             contest_identifier,
         });
     }
+
+    /// Warn if the submit id is not in the refreshed language list of the platform,
+    /// hard-coded languages are not checked since they may be outdated themselves.
+    fn check_submit_language(platform: Platform, language_id: &str) {
+        if OnlineJudge::get_platform_languages_updated_time(platform).is_none() {
+            return;
+        }
+        let languages = OnlineJudge::get_platform_languages(platform);
+        if languages.iter().any(|x| x.id == language_id) {
+            return;
+        }
+        println!(
+            "{}",
+            format!(
+                "Submit language {} is not offered by {} anymore, add the language config again with `ace lang add`.",
+                language_id, platform
+            )
+            .yellow()
+        );
+    }
     fn get_submit_language_id(filename: &str, platform: Platform) -> Result<String, String> {
         let language_configs =
             match Utility::get_language_config_by_filename_and_platform(filename, platform) {
@@ -212,17 +381,37 @@ impl SubmitCommand {
 
 #[tokio::test]
 async fn test_show_result() {
+    let mut shown_lines = 0;
     for idx in 0..20 {
         let submission_info = PostSubmissionInfo {
             submission_id: random_str::get_string(10, true, true, true, true),
             contest_identifier: "123789".to_string(),
             problem_identifier: "A".to_string(),
-            verdict: Verdict::Waiting,
+            verdict: match idx {
+                19 => Verdict::Resulted,
+                _ => Verdict::Waiting,
+            },
             verdict_info: "Accepted".to_string(),
             execute_time: "100ms".to_string(),
             execute_memory: "100MB".to_string(),
+            language: "GNU C++20 (64)".to_string(),
+            submit_time: "2023-08-20 16:44:00".to_string(),
+            failed_test: match idx {
+                19 => Some(String::from("3")),
+                _ => None,
+            },
+            judge_test_cases: Vec::new(),
         };
-        SubmitCommand::show_result(&submission_info, idx as u32).await;
+        let lines = SubmitCommand::show_result(&submission_info, shown_lines).await;
+        // The failed test adds a row to the final table.
+        assert_eq!(
+            lines,
+            match idx {
+                19 => 7,
+                _ => 6,
+            }
+        );
+        shown_lines = lines;
         tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
     }
 }
@@ -1,13 +1,14 @@
 use super::model::TestArgs;
 use crate::context::CONTEXT;
 use crate::database::CONFIG_DB;
+use crate::model::ProblemMetadata;
 use crate::snippet::Snippet;
 use crate::utility::diff::Difference;
+use crate::utility::executor::{ExecuteStatus, Executor};
 use crate::utility::Utility;
 use colored::Colorize;
 use inquire::Select;
 use std::env::current_dir;
-use std::io::{Read, Write};
 use std::path::Path;
 use std::process::{Command, Stdio};
 use tokio::fs;
@@ -163,6 +164,42 @@ impl TestCommand {
                 return Err(info);
             }
         };
+        let metadata = match current_dir() {
+            Ok(current_dir) => match Utility::load_problem_metadata(&current_dir) {
+                Ok(metadata) => metadata,
+                Err(info) => {
+                    log::warn!("Load problem metadata failed: {}", info);
+                    ProblemMetadata::default()
+                }
+            },
+            Err(_) => ProblemMetadata::default(),
+        };
+        if metadata.interactive {
+            println!(
+                "{}",
+                "This is an interactive problem, samples can't be tested without an interactor."
+                    .yellow()
+            );
+            log::info!("Clear with command: {}", clear_command.bright_blue());
+            if let Err(info) = Self::run_no_input_command(clear_command) {
+                return Err(info);
+            }
+            return Ok("Skip test for interactive problem".to_string());
+        }
+        if metadata.special_judge {
+            println!(
+                "{}",
+                "Any valid answer is accepted in this problem, different output may still be correct."
+                    .yellow()
+            );
+        }
+        if let (Some(time_limit), Some(memory_limit)) = (metadata.time_limit, metadata.memory_limit)
+        {
+            println!(
+                "Time limit: {} ms, memory limit: {} MB",
+                time_limit, memory_limit
+            );
+        }
 
         // Run test command
         log::info!("Test with command: {}", execute_command.bright_blue());
@@ -181,50 +218,72 @@ impl TestCommand {
                     return Err(info.to_string());
                 }
             };
-            let mut command = match cfg!(target_os = "windows") {
-                true => Command::new("powershell"),
-                false => Command::new("sh"),
+            let result = match Executor::execute(
+                execute_command,
+                &file_in,
+                metadata.time_limit,
+                metadata.memory_limit,
+            ) {
+                Ok(result) => result,
+                Err(info) => {
+                    return Err(info);
+                }
             };
-            let child = command
-                .args(["-c", execute_command])
-                .stdin(Stdio::piped())
-                .stdout(Stdio::piped())
-                .spawn()
-                .expect("failed to execute process");
-            match child.stdin {
-                Some(mut stdin) => match stdin.write_all(file_in.as_bytes()) {
-                    Ok(_) => {}
-                    Err(info) => {
-                        return Err(info.to_string());
-                    }
-                },
-                None => {
-                    return Err("Cannot get stdin".to_string());
+            let usage = match result.memory {
+                Some(memory) => format!("{} ms, {} KB", result.elapsed.as_millis(), memory),
+                None => format!("{} ms", result.elapsed.as_millis()),
+            };
+            match result.status {
+                ExecuteStatus::TimeLimitExceeded => {
+                    println!(
+                        "Time limit exceeded with input file: {} ({})",
+                        input_file.red(),
+                        usage
+                    );
+                    return Err(format!(
+                        "Time limit exceeded with input file: {}",
+                        input_file.bright_blue()
+                    ));
                 }
-            }
-            match child.stdout {
-                Some(mut stdout) => {
-                    let mut stdout_str = String::new();
-                    match stdout.read_to_string(&mut stdout_str) {
-                        Ok(_) => {}
-                        Err(info) => {
-                            return Err(info.to_string());
-                        }
-                    }
-                    let same = Difference::is_same(&file_out, &stdout_str);
-                    if same {
-                        println!("Test success with input file: {}", input_file.bright_blue());
-                    } else {
-                        println!("Test failed with input file: {}", input_file.red());
-                        return Err(format!(
-                            "Test failed with input file: {}",
-                            input_file.bright_blue()
-                        ));
-                    }
+                ExecuteStatus::MemoryLimitExceeded => {
+                    println!(
+                        "Memory limit exceeded with input file: {} ({})",
+                        input_file.red(),
+                        usage
+                    );
+                    return Err(format!(
+                        "Memory limit exceeded with input file: {}",
+                        input_file.bright_blue()
+                    ));
                 }
-                None => {
-                    return Err("Cannot get stdout".to_string());
+                ExecuteStatus::Finished => {}
+            }
+            let same = match metadata.error_tolerance {
+                Some(tolerance) => {
+                    Difference::is_same_with_tolerance(&file_out, &result.stdout, tolerance)
                 }
+                None => Difference::is_same(&file_out, &result.stdout),
+            };
+            if !same && metadata.special_judge {
+                println!(
+                    "Output differs with input file: {} ({}), check it manually.",
+                    input_file.yellow(),
+                    usage
+                );
+                continue;
+            }
+            if same {
+                println!(
+                    "Test success with input file: {} ({})",
+                    input_file.bright_blue(),
+                    usage
+                );
+            } else {
+                println!("Test failed with input file: {}", input_file.red());
+                return Err(format!(
+                    "Test failed with input file: {}",
+                    input_file.bright_blue()
+                ));
             }
         }
 
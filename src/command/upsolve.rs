@@ -0,0 +1,192 @@
+use std::path::Path;
+
+use colored::Colorize;
+
+use super::model::UpsolveArgs;
+use crate::constants::PLATFORM_MAP;
+use crate::database::CONFIG_DB;
+use crate::model::{Platform, PostSubmissionInfo};
+use crate::platform::OnlineJudge;
+
+pub struct UpsolveCommand {}
+
+impl UpsolveCommand {
+    pub async fn handle(args: UpsolveArgs) -> Result<String, String> {
+        let platform = match args.platform {
+            Some(platform) => match PLATFORM_MAP.get(platform.as_str()) {
+                Some(platform) => Some(*platform),
+                None => {
+                    return Err(format!("Platform {} not found", platform));
+                }
+            },
+            None => None,
+        };
+        let workspace = match CONFIG_DB.get_config("workspace") {
+            Ok(workspace) => workspace,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        let contests = Self::get_local_contests(Path::new(&workspace))
+            .into_iter()
+            .filter(|(x, _, _)| platform.is_none() || platform == Some(*x))
+            .collect::<Vec<_>>();
+        if contests.is_empty() {
+            println!("No contest found in workspace, run `ace parse` first.");
+            return Ok(String::from("Upsolve command success"));
+        }
+        let mut unsolved_count = 0;
+        let mut contest_count = 0;
+        let mut current_platform = None;
+        let mut oj = None;
+        for (platform, contest_identifier, problems) in contests {
+            if current_platform != Some(platform) {
+                let account_info = match CONFIG_DB.get_default_account(platform) {
+                    Ok(account_info) => account_info,
+                    Err(info) => {
+                        log::warn!("Skip contests on {}, {}", platform, info);
+                        current_platform = Some(platform);
+                        oj = None;
+                        continue;
+                    }
+                };
+                current_platform = Some(platform);
+                oj = match OnlineJudge::new(account_info, platform) {
+                    Ok(oj) => Some(oj),
+                    Err(info) => {
+                        log::warn!("Skip contests on {}, {}", platform, info);
+                        continue;
+                    }
+                };
+            }
+            let oj = match oj.as_mut() {
+                Some(oj) => oj,
+                None => continue,
+            };
+            let submissions = match oj.get_accepted_submissions(&contest_identifier).await {
+                Ok(submissions) => submissions,
+                Err(info) => {
+                    log::warn!(
+                        "Get submissions of {} {} failed, {}",
+                        platform,
+                        contest_identifier,
+                        info
+                    );
+                    continue;
+                }
+            };
+            let unsolved = Self::get_unsolved_problems(&problems, &submissions);
+            if unsolved.is_empty() {
+                continue;
+            }
+            println!(
+                "{} {} ({} unsolved)",
+                platform,
+                contest_identifier.bright_blue(),
+                unsolved.len()
+            );
+            for problem in unsolved.iter() {
+                println!(
+                    "  {:<4} {}",
+                    problem.to_uppercase(),
+                    OnlineJudge::get_problem_url(platform, &contest_identifier, problem)
+                );
+            }
+            unsolved_count += unsolved.len();
+            contest_count += 1;
+        }
+        match unsolved_count {
+            0 => println!("{}", "All parsed problems are solved.".green()),
+            _ => println!(
+                "{} unsolved problems in {} contests.",
+                unsolved_count.to_string().yellow(),
+                contest_count
+            ),
+        }
+        return Ok(String::from("Upsolve command success"));
+    }
+
+    /// Find contests parsed into workspace, in layout `platform/contest/problem`.
+    /// # Returns
+    /// * `Vec<(Platform, String, Vec<String>)>` - Platform, contest identifier and problem identifiers.
+    fn get_local_contests(workspace: &Path) -> Vec<(Platform, String, Vec<String>)> {
+        let mut contests = Vec::new();
+        for platform_dir in Self::read_sub_dirs(workspace) {
+            let platform = match PLATFORM_MAP.get(platform_dir.to_lowercase().as_str()) {
+                Some(platform) => *platform,
+                None => continue,
+            };
+            let platform_path = workspace.join(&platform_dir);
+            for contest_identifier in Self::read_sub_dirs(&platform_path) {
+                let problems = Self::read_sub_dirs(&platform_path.join(&contest_identifier));
+                if problems.is_empty() {
+                    continue;
+                }
+                contests.push((platform, contest_identifier, problems));
+            }
+        }
+        return contests;
+    }
+
+    /// Names of sub directories in sorted order, hidden ones are skipped.
+    fn read_sub_dirs(path: &Path) -> Vec<String> {
+        let mut names = match std::fs::read_dir(path) {
+            Ok(entries) => entries
+                .filter_map(|x| x.ok())
+                .filter(|x| x.path().is_dir())
+                .filter_map(|x| x.file_name().to_str().map(|x| x.to_string()))
+                .filter(|x| !x.starts_with('.'))
+                .collect::<Vec<_>>(),
+            Err(_) => Vec::new(),
+        };
+        names.sort();
+        return names;
+    }
+
+    fn get_unsolved_problems(
+        problems: &[String],
+        submissions: &[PostSubmissionInfo],
+    ) -> Vec<String> {
+        return problems
+            .iter()
+            .filter(|problem| {
+                !submissions
+                    .iter()
+                    .any(|x| x.problem_identifier.eq_ignore_ascii_case(problem))
+            })
+            .cloned()
+            .collect();
+    }
+}
+
+#[test]
+fn test_get_unsolved_problems() {
+    let submission = |problem_identifier: &str| {
+        let mut submission = PostSubmissionInfo::new();
+        submission.problem_identifier = problem_identifier.to_string();
+        submission
+    };
+    let problems = vec![String::from("a"), String::from("b"), String::from("c")];
+    let submissions = vec![submission("A"), submission("C"), submission("C")];
+    assert_eq!(
+        UpsolveCommand::get_unsolved_problems(&problems, &submissions),
+        vec![String::from("b")]
+    );
+}
+
+#[test]
+fn test_get_local_contests() {
+    let workspace = std::env::temp_dir().join("ace_test_upsolve");
+    let _ = std::fs::remove_dir_all(&workspace);
+    std::fs::create_dir_all(workspace.join("Codeforces").join("1860").join("b")).unwrap();
+    std::fs::create_dir_all(workspace.join("Codeforces").join("1860").join("a")).unwrap();
+    std::fs::create_dir_all(workspace.join("Codeforces").join("1861")).unwrap();
+    std::fs::create_dir_all(workspace.join("AtCoder").join("abc300").join("a")).unwrap();
+    std::fs::create_dir_all(workspace.join("templates").join("cpp").join("a")).unwrap();
+    let contests = UpsolveCommand::get_local_contests(&workspace);
+    assert_eq!(contests.len(), 2);
+    assert_eq!(contests[0].0, Platform::AtCoder);
+    assert_eq!(contests[1].1, "1860");
+    assert_eq!(contests[1].2, vec![String::from("a"), String::from("b")]);
+    let _ = std::fs::remove_dir_all(&workspace);
+}
@@ -0,0 +1,257 @@
+use colored::Colorize;
+use prettytable::{row, Table};
+
+use super::model::{VirtualArgs, VirtualOptions};
+use super::parse::ParseCommand;
+use crate::constants::PLATFORM_MAP;
+use crate::database::CONFIG_DB;
+use crate::model::{ContestStatus, ScoringRule, Verdict, VirtualContest};
+use crate::platform::error::OnlineJudgeError;
+use crate::platform::OnlineJudge;
+use crate::utility::virtual_contest::{VirtualContestUtility, VirtualSummary};
+
+pub struct VirtualContestCommand {}
+
+impl VirtualContestCommand {
+    pub async fn handle(args: VirtualArgs) -> Result<String, OnlineJudgeError> {
+        match args.options {
+            VirtualOptions::Start {
+                platform,
+                contest_identifier,
+            } => Self::start(&platform, &contest_identifier).await,
+            VirtualOptions::Status => Self::status().await,
+            VirtualOptions::End => Self::end().await,
+        }
+    }
+
+    async fn start(platform: &str, contest_identifier: &str) -> Result<String, OnlineJudgeError> {
+        match VirtualContestUtility::load() {
+            Ok(Some(virtual_contest)) => {
+                return Err(format!(
+                    "Virtual contest {} is running, run `ace virtual end` first",
+                    virtual_contest.title.trim()
+                )
+                .into());
+            }
+            Ok(None) => {}
+            Err(info) => {
+                return Err(info.into());
+            }
+        }
+        let real_platform = match PLATFORM_MAP.get(platform) {
+            Some(platform) => *platform,
+            None => {
+                return Err(format!("Platform {} not found", platform).into());
+            }
+        };
+        let account_info = match CONFIG_DB.get_default_account(real_platform) {
+            Ok(account_info) => account_info,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let mut oj = match OnlineJudge::new(account_info, real_platform) {
+            Ok(oj) => oj,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let contest = match oj.get_contest(contest_identifier).await {
+            Ok(contest) => contest,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        if contest.status != ContestStatus::Ended {
+            return Err(format!(
+                "Contest {} is not ended, virtual contest is for ended contests",
+                contest.title.trim()
+            )
+            .into());
+        }
+        // Problems and scoring rule come from the standings, so that the summary
+        // is comparable with the official results.
+        let standings = match oj.get_standings(contest_identifier, &[]).await {
+            Ok(standings) => standings,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        if let Err(info) = ParseCommand::parse_contest(&mut oj, contest_identifier).await {
+            return Err(info);
+        }
+        let virtual_contest = VirtualContest {
+            platform: real_platform,
+            contest_identifier: contest_identifier.to_string(),
+            title: contest.title.trim().to_string(),
+            start_time: chrono::Utc::now().timestamp(),
+            duration: (contest.end_time - contest.start_time).num_seconds(),
+            problems: standings.problems,
+            problem_points: standings.problem_points,
+            rule: standings.rule,
+            submissions: Vec::new(),
+        };
+        if let Err(info) = VirtualContestUtility::save(&virtual_contest) {
+            return Err(info.into());
+        }
+        println!(
+            "Virtual contest {} started, it ends in {}.",
+            virtual_contest.title.bright_blue(),
+            VirtualContestUtility::format_elapsed(virtual_contest.duration).green()
+        );
+        return Ok(String::from("Virtual contest start success"));
+    }
+
+    async fn status() -> Result<String, OnlineJudgeError> {
+        let virtual_contest = match Self::load_and_refresh().await {
+            Ok(virtual_contest) => virtual_contest,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        Self::show_progress(&virtual_contest);
+        return Ok(String::from("Virtual contest status success"));
+    }
+
+    async fn end() -> Result<String, OnlineJudgeError> {
+        let virtual_contest = match Self::load_and_refresh().await {
+            Ok(virtual_contest) => virtual_contest,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        let summary = Self::show_progress(&virtual_contest);
+        let account_info = match CONFIG_DB.get_default_account(virtual_contest.platform) {
+            Ok(account_info) => account_info,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let mut oj = match OnlineJudge::new(account_info, virtual_contest.platform) {
+            Ok(oj) => oj,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let standings = match oj
+            .get_full_standings(&virtual_contest.contest_identifier)
+            .await
+        {
+            Ok(standings) => standings,
+            Err(info) => {
+                return Err(format!("Get standings failed, {}", info).into());
+            }
+        };
+        let rank = VirtualContestUtility::get_rank(&standings, &summary);
+        println!(
+            "Rank {} of {} participants in {}.",
+            rank.to_string().green().bold(),
+            standings.rows.len(),
+            virtual_contest.title.bright_blue()
+        );
+        if let Err(info) = VirtualContestUtility::remove() {
+            return Err(info.into());
+        }
+        return Ok(String::from("Virtual contest end success"));
+    }
+
+    /// Load the running virtual contest, and refresh verdicts of submissions
+    /// which were not judged when submitted.
+    async fn load_and_refresh() -> Result<VirtualContest, OnlineJudgeError> {
+        let mut virtual_contest = match VirtualContestUtility::load() {
+            Ok(Some(virtual_contest)) => virtual_contest,
+            Ok(None) => {
+                return Err(String::from(
+                    "No virtual contest is running, run `ace virtual start` first",
+                )
+                .into());
+            }
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        if virtual_contest.submissions.iter().all(|x| x.judged) {
+            return Ok(virtual_contest);
+        }
+        let account_info = match CONFIG_DB.get_default_account(virtual_contest.platform) {
+            Ok(account_info) => account_info,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let mut oj = match OnlineJudge::new(account_info, virtual_contest.platform) {
+            Ok(oj) => oj,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        if let Err(info) = oj.login().await {
+            return Err(info);
+        }
+        for submission in virtual_contest.submissions.iter_mut() {
+            if submission.judged {
+                continue;
+            }
+            match oj
+                .retrive_result(&submission.problem_identifier, &submission.submission_id)
+                .await
+            {
+                Ok(result) => {
+                    submission.judged = result.verdict != Verdict::Waiting;
+                    submission.verdict_info = result.verdict_info;
+                }
+                Err(info) => {
+                    log::warn!(
+                        "Refresh submission {} failed, {}",
+                        submission.submission_id,
+                        info
+                    );
+                }
+            }
+        }
+        if let Err(info) = VirtualContestUtility::save(&virtual_contest) {
+            return Err(info.into());
+        }
+        return Ok(virtual_contest);
+    }
+
+    /// Show elapsed time and results of problems, submissions not judged yet are not counted.
+    pub fn show_progress(virtual_contest: &VirtualContest) -> VirtualSummary {
+        let summary = VirtualContestUtility::summarize(virtual_contest);
+        let mut table = Table::new();
+        table.add_row(row!["problem", "result", "accepted time"]);
+        for problem in summary.problems.iter() {
+            let result = match (problem.accepted_elapsed, virtual_contest.rule) {
+                (Some(_), ScoringRule::Icpc) if problem.rejected == 0 => String::from("+"),
+                (Some(_), ScoringRule::Icpc) => format!("+{}", problem.rejected),
+                (Some(_), _) => format!("{}", problem.score),
+                (None, _) if problem.rejected == 0 => String::new(),
+                (None, _) => format!("-{}", problem.rejected),
+            };
+            let accepted_time = match problem.accepted_elapsed {
+                Some(elapsed) => VirtualContestUtility::format_elapsed(elapsed),
+                None => String::new(),
+            };
+            match problem.accepted_elapsed {
+                Some(_) => table.add_row(row![problem.problem, Fg->result, accepted_time]),
+                None => table.add_row(row![problem.problem, Fr->result, accepted_time]),
+            };
+        }
+        println!(
+            "Virtual contest {}, elapsed {} of {}.",
+            virtual_contest.title.bright_blue(),
+            VirtualContestUtility::format_elapsed(VirtualContestUtility::get_elapsed(
+                virtual_contest
+            ))
+            .green(),
+            VirtualContestUtility::format_elapsed(virtual_contest.duration)
+        );
+        table.printstd();
+        println!(
+            "score: {}, penalty: {}",
+            summary.score.to_string().bold(),
+            VirtualContestUtility::format_elapsed(summary.penalty)
+        );
+        return summary;
+    }
+}
@@ -0,0 +1,72 @@
+use super::model::WatchArgs;
+use super::submit::SubmitCommand;
+use crate::constants::PLATFORM_MAP;
+use crate::database::CONFIG_DB;
+use crate::platform::error::OnlineJudgeError;
+use crate::platform::OnlineJudge;
+use crate::utility::Utility;
+
+pub struct WatchCommand {}
+
+impl WatchCommand {
+    pub async fn handle(args: WatchArgs) -> Result<String, OnlineJudgeError> {
+        let (platform, contest_identifier) = match (args.platform, args.contest_identifier) {
+            (Some(platform), Some(contest_identifier)) => {
+                match PLATFORM_MAP.get(platform.as_str()) {
+                    Some(platform) => (*platform, contest_identifier),
+                    None => {
+                        return Err(format!("Platform {} not found", platform).into());
+                    }
+                }
+            }
+            _ => match Utility::get_identifiers_from_current_dir() {
+                Ok((platform, contest_identifier, _)) => (platform, contest_identifier),
+                Err(info) => {
+                    return Err(format!(
+                        "Platform and contest are required outside of problem directory, {}",
+                        info
+                    )
+                    .into());
+                }
+            },
+        };
+        let account_info = match CONFIG_DB.get_default_account(platform) {
+            Ok(account_info) => account_info,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let mut oj = match OnlineJudge::new(account_info, platform) {
+            Ok(oj) => oj,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        // Problem of the submission is only known from its page.
+        let (submission_info, _) = match oj
+            .fetch_submission(&contest_identifier, &args.submission_id)
+            .await
+        {
+            Ok(submission) => submission,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        let full_problem_identifier = format!(
+            "{}_{}",
+            contest_identifier, submission_info.problem_identifier
+        );
+        let timeout = SubmitCommand::get_poll_timeout(args.timeout);
+        return match SubmitCommand::watch_result(
+            &mut oj,
+            &full_problem_identifier,
+            &args.submission_id,
+            timeout,
+        )
+        .await
+        {
+            Ok(_) => Ok(String::from("Watch command success")),
+            Err(info) => Err(info),
+        };
+    }
+}
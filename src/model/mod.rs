@@ -21,6 +21,14 @@ pub struct PostSubmissionInfo {
     pub verdict_info: String,
     pub execute_time: String,
     pub execute_memory: String,
+    pub language: String,
+    /// Submit time in local time zone.
+    pub submit_time: String,
+    /// The first failed test, its number counted from 1 on Codeforces,
+    /// or its name on AtCoder as judge test cases are sorted by name.
+    pub failed_test: Option<String>,
+    /// Verdicts of each judge test case, empty if the platform doesn't show them.
+    pub judge_test_cases: Vec<JudgeTestCase>,
 }
 impl PostSubmissionInfo {
     pub fn new() -> PostSubmissionInfo {
@@ -32,10 +40,81 @@ impl PostSubmissionInfo {
             verdict_info: String::new(),
             execute_time: String::new(),
             execute_memory: String::new(),
+            language: String::new(),
+            submit_time: String::new(),
+            failed_test: None,
+            judge_test_cases: Vec::new(),
         }
     }
 }
 
+/// Result of running code with custom input on the judge.
+#[derive(Debug)]
+pub struct CustomTestResult {
+    pub verdict: Verdict,
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_code: Option<i64>,
+    pub execute_time: String,
+    pub execute_memory: String,
+}
+
+/// Hack of a submission in the hacks table of a contest.
+#[derive(Debug)]
+pub struct HackInfo {
+    pub hack_id: String,
+    pub hacker: String,
+    pub defender: String,
+    pub problem_identifier: String,
+    pub verdict: Verdict,
+    pub verdict_info: String,
+}
+
+/// Source code downloaded from submission page.
+#[derive(Debug)]
+pub struct SubmissionSource {
+    pub problem_identifier: String,
+    pub code: String,
+}
+
+#[derive(Debug)]
+pub struct JudgeTestCase {
+    pub name: String,
+    pub verdict_info: String,
+    pub execute_time: String,
+    pub execute_memory: String,
+}
+
+#[derive(Debug)]
+pub struct Standings {
+    /// Problem indexes, such as `A`, `B`.
+    pub problems: Vec<String>,
+    /// Full points of problems in the order of `problems`.
+    pub problem_points: Vec<f64>,
+    pub rule: ScoringRule,
+    pub rows: Vec<StandingsRow>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum ScoringRule {
+    /// Solved problems, penalty is the sum of accepted time and 10 minutes per rejected attempt.
+    Icpc,
+    /// Points decrease with time and 50 points off per rejected attempt, no penalty.
+    Codeforces,
+    /// Sum of points, penalty is the last accepted time and 5 minutes per rejected attempt.
+    AtCoder,
+}
+
+#[derive(Debug)]
+pub struct StandingsRow {
+    pub rank: u64,
+    pub handle: String,
+    pub score: String,
+    pub penalty: String,
+    /// Results in the order of problems, such as `+`, `+2`, `-1`, `500`, empty if not tried.
+    pub problem_results: Vec<String>,
+}
+
 #[derive(Debug, PartialEq)]
 pub enum ContestStatus {
     NotStarted = 0,
@@ -49,9 +128,11 @@ pub struct Contest {
     pub start_time: DateTime<Utc>,
     pub end_time: DateTime<Utc>,
     pub status: ContestStatus,
+    /// Registration state, empty if unknown.
+    pub registration: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Copy, Display, Debug, EnumString, EnumIter)]
+#[derive(Serialize, Deserialize, Clone, Copy, Display, Debug, EnumString, EnumIter, PartialEq)]
 pub enum Platform {
     Codeforces,
     AtCoder,
@@ -113,6 +194,41 @@ pub struct TestCase {
     pub output: String,
 }
 
+#[derive(Debug)]
+pub struct ProblemDetail {
+    pub test_cases: Vec<TestCase>,
+    /// Problem statement in markdown.
+    pub statement: String,
+    pub metadata: ProblemMetadata,
+}
+
+/// Per-problem metadata, saved in problem directory as `metadata.json`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ProblemMetadata {
+    /// Time limit in milliseconds.
+    pub time_limit: Option<u64>,
+    /// Memory limit in megabytes.
+    pub memory_limit: Option<u64>,
+    /// Interactive problem, samples can't be tested locally without an interactor.
+    pub interactive: bool,
+    /// Any valid answer is accepted, so the sample output is only one of the answers.
+    pub special_judge: bool,
+    /// Allowed absolute or relative error of real numbers in output.
+    pub error_tolerance: Option<f64>,
+    /// Rating on Codeforces, or estimated difficulty on AtCoder.
+    pub difficulty: Option<i64>,
+    pub tags: Vec<String>,
+}
+
+/// Difficulty and tags of a problem in the problem dataset of a platform.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ProblemDifficulty {
+    pub difficulty: Option<i64>,
+    pub tags: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PlatformLanguage {
     pub language: ProgramLanguage,
@@ -126,3 +242,31 @@ impl Display for PlatformLanguage {
         write!(f, "{}", self.description)
     }
 }
+
+/// Virtual participation of an ended contest.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VirtualContest {
+    pub platform: Platform,
+    pub contest_identifier: String,
+    pub title: String,
+    /// Unix timestamp in seconds.
+    pub start_time: i64,
+    /// Duration of the contest in seconds.
+    pub duration: i64,
+    pub problems: Vec<String>,
+    pub problem_points: Vec<f64>,
+    pub rule: ScoringRule,
+    pub submissions: Vec<VirtualSubmission>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VirtualSubmission {
+    pub submission_id: String,
+    /// Full problem identifier, such as `1860_c`.
+    pub problem_identifier: String,
+    /// Seconds since the start of virtual contest.
+    pub elapsed: i64,
+    pub verdict_info: String,
+    /// Whether the verdict is final, waiting ones are refreshed later.
+    pub judged: bool,
+}
@@ -1,9 +1,16 @@
 use std::collections::HashMap;
 
+use crate::model::CustomTestResult;
+use crate::model::HackInfo;
 use crate::model::PlatformLanguage;
 use crate::model::PostSubmissionInfo;
+use crate::model::ProblemDifficulty;
+use crate::model::ProblemMetadata;
+use crate::model::Standings;
+use crate::model::SubmissionSource;
 use crate::model::TestCase;
 use crate::model::{Platform, Contest};
+use crate::utility::language::LanguageUtility;
 mod constants;
 mod parser;
 mod utility;
@@ -26,6 +33,14 @@ impl OnlineJudgeBehavior for AtCoder {
         return resp.contains(r#"</span> Sign Out</a></li>"#);
     }
 
+    fn get_login_handle(resp: &str) -> Option<String> {
+        return Utility::get_login_handle(resp);
+    }
+
+    fn get_session_cookie_name() -> String {
+        return String::from("REVEL_SESSION");
+    }
+
     fn build_login_page_url() -> String {
         return String::from("https://atcoder.jp/login");
     }
@@ -46,6 +61,65 @@ impl OnlineJudgeBehavior for AtCoder {
         return data;
     }
 
+    fn build_custom_test_page_url(contest_identifier: &str) -> String {
+        return format!(
+            "https://atcoder.jp/contests/{}/custom_test",
+            contest_identifier
+        );
+    }
+
+    fn build_custom_test_url(contest_identifier: &str) -> String {
+        return format!(
+            "https://atcoder.jp/contests/{}/custom_test/submit/json",
+            contest_identifier
+        );
+    }
+
+    fn build_custom_test_request_data(
+        code: &str,
+        lang_id: &str,
+        input: &str,
+        csrf_token: &str,
+    ) -> HashMap<String, String> {
+        let mut data = HashMap::new();
+        data.insert("data.LanguageId".to_string(), lang_id.to_string());
+        data.insert("sourceCode".to_string(), code.to_string());
+        data.insert("input".to_string(), input.to_string());
+        data.insert("csrf_token".to_string(), csrf_token.to_string());
+        return data;
+    }
+
+    fn build_custom_test_result_url(contest_identifier: &str) -> String {
+        return format!(
+            "https://atcoder.jp/contests/{}/custom_test/json?reload=true",
+            contest_identifier
+        );
+    }
+
+    fn build_custom_test_result_request_data(
+        _custom_test_id: &str,
+        _csrf_token: &str,
+    ) -> Option<HashMap<String, String>> {
+        return None;
+    }
+
+    fn build_hack_url(_contest_identifier: &str) -> Option<String> {
+        return None;
+    }
+
+    fn build_hack_list_url(contest_identifier: &str) -> String {
+        return Self::build_contest_url(contest_identifier);
+    }
+
+    fn build_hack_request_data(
+        _submission_id: &str,
+        _input: &str,
+        _generator: Option<(&str, &str)>,
+        _csrf_token: &str,
+    ) -> HashMap<String, String> {
+        return HashMap::new();
+    }
+
     fn build_contest_url(contest_identifier: &str) -> String {
         return String::from(format!(
             "https://atcoder.jp/contests/{}",
@@ -53,6 +127,27 @@ impl OnlineJudgeBehavior for AtCoder {
         ));
     }
 
+    fn build_contest_list_url() -> String {
+        return String::from("https://atcoder.jp/contests/");
+    }
+
+    fn build_language_list_url() -> String {
+        return String::from("https://atcoder.jp/contests/practice/submit");
+    }
+
+    fn build_problem_url(contest_identifier: &str, problem_identifier: &str) -> String {
+        return format!(
+            "https://atcoder.jp/contests/{}/tasks/{}_{}",
+            contest_identifier,
+            contest_identifier,
+            problem_identifier.to_lowercase()
+        );
+    }
+
+    fn build_problem_difficulty_url() -> String {
+        return String::from("https://kenkoooo.com/atcoder/resources/problem-models.json");
+    }
+
     fn build_problem_list_url(contest_identifier: &str) -> String {
         return String::from(format!(
             "https://atcoder.jp/contests/{}/tasks",
@@ -81,6 +176,42 @@ impl OnlineJudgeBehavior for AtCoder {
         ));
     }
 
+    fn build_submission_list_url(contest_identifier: &str) -> String {
+        return format!(
+            "https://atcoder.jp/contests/{}/submissions/me",
+            contest_identifier
+        );
+    }
+
+    fn build_accepted_submission_list_url(
+        contest_identifier: &str,
+        _username: &str,
+        page: usize,
+    ) -> Option<String> {
+        return Some(format!(
+            "https://atcoder.jp/contests/{}/submissions/me?f.Status=AC&page={}",
+            contest_identifier, page
+        ));
+    }
+
+    fn build_standings_url(contest_identifier: &str, _handles: &[String]) -> String {
+        return format!(
+            "https://atcoder.jp/contests/{}/standings/json",
+            contest_identifier
+        );
+    }
+
+    fn build_full_standings_url(contest_identifier: &str) -> String {
+        return format!(
+            "https://atcoder.jp/contests/{}/standings/json",
+            contest_identifier
+        );
+    }
+
+    fn build_user_submission_list_url(_username: &str) -> Option<String> {
+        return None;
+    }
+
     fn parse_problem_list(
         contest_identifier: &str,
         resp: &str,
@@ -92,6 +223,28 @@ impl OnlineJudgeBehavior for AtCoder {
         return HtmlParser::parse_test_cases(resp);
     }
 
+    fn parse_statement(resp: &str) -> Result<String, String> {
+        return HtmlParser::parse_statement(resp);
+    }
+
+    fn parse_problem_metadata(resp: &str) -> Result<ProblemMetadata, String> {
+        return HtmlParser::parse_problem_metadata(resp);
+    }
+
+    fn parse_problem_difficulties(
+        resp: &str,
+    ) -> Result<HashMap<String, ProblemDifficulty>, String> {
+        return HtmlParser::parse_problem_difficulties(resp);
+    }
+
+    fn get_problem_difficulty_key(full_problem_identifier: &str, problem_url: &str) -> String {
+        // Task id in url, which differs from the problem identifier in some old contests.
+        return match problem_url.rsplit_once('/') {
+            Some((_, task_id)) => task_id.to_lowercase(),
+            None => full_problem_identifier.to_lowercase(),
+        };
+    }
+
     fn parse_submission_page(
         contest_identifier: &str,
         problem_identifier: &str,
@@ -105,6 +258,19 @@ impl OnlineJudgeBehavior for AtCoder {
         return HtmlParser::parse_recent_submission_id(resp);
     }
 
+    fn parse_submission_source(resp: &str) -> Result<SubmissionSource, String> {
+        return HtmlParser::parse_submission_source(resp);
+    }
+
+    fn parse_submission_list(resp: &str) -> Result<Vec<PostSubmissionInfo>, String> {
+        return HtmlParser::parse_submission_list(resp);
+    }
+
+    fn parse_accepted_submission_list(resp: &str) -> Result<Vec<PostSubmissionInfo>, String> {
+        // Submissions are filtered by status in the url.
+        return HtmlParser::parse_submission_list(resp);
+    }
+
     fn parse_contest(
         contest_identifier: &str,
         resp: &str,
@@ -112,6 +278,42 @@ impl OnlineJudgeBehavior for AtCoder {
         return HtmlParser::parse_contest(contest_identifier, resp);
     }
 
+    fn parse_standings(resp: &str, handles: &[String]) -> Result<Standings, String> {
+        return HtmlParser::parse_standings(resp, handles);
+    }
+
+    fn parse_platform_languages(resp: &str) -> Result<Vec<PlatformLanguage>, String> {
+        return match HtmlParser::parse_language_options(resp) {
+            Ok(options) => Ok(LanguageUtility::match_platform_languages(
+                Platform::AtCoder,
+                options,
+                &Self::get_platform_languages(),
+            )),
+            Err(info) => Err(info),
+        };
+    }
+
+    fn parse_hack_list(_resp: &str) -> Result<Vec<HackInfo>, String> {
+        return Err(String::from("Hacks are not supported on AtCoder."));
+    }
+
+    fn parse_hack_result(_resp: &str) -> Result<(), String> {
+        return Err(String::from("Hacks are not supported on AtCoder."));
+    }
+
+    fn parse_contest_list(resp: &str) -> Result<Vec<Contest>, String> {
+        return HtmlParser::parse_contest_list(resp);
+    }
+
+    fn parse_custom_test_id(_resp: &str) -> Result<String, String> {
+        // Only the latest custom test of the account is kept.
+        return Ok(String::new());
+    }
+
+    fn parse_custom_test_result(resp: &str) -> Result<CustomTestResult, String> {
+        return HtmlParser::parse_custom_test_result(resp);
+    }
+
     fn get_platform_languages() -> Vec<PlatformLanguage> {
         let mut vec = Vec::new();
         for (id, description, language) in constants::LANG.iter() {
@@ -1,7 +1,14 @@
+use std::collections::HashMap;
+
 use chrono::Utc;
-use scraper::{Html, Selector};
+use scraper::{ElementRef, Html, Selector};
 
-use crate::model::{Contest, ContestStatus, PostSubmissionInfo, TestCase, Verdict};
+use crate::model::{
+    Contest, ContestStatus, CustomTestResult, JudgeTestCase, PostSubmissionInfo, ProblemDifficulty,
+    ProblemMetadata, ScoringRule, Standings, StandingsRow, SubmissionSource, TestCase, Verdict,
+};
+use crate::utility::markdown::Markdown;
+use crate::utility::problem_type::ProblemType;
 
 use super::utility::Utility;
 
@@ -58,6 +65,14 @@ impl HtmlParser {
         submission_info.submission_id = String::from(submission_id);
         submission_info.contest_identifier = String::from(contest_identifier);
         submission_info.problem_identifier = String::from(problem_identifier);
+        submission_info.submit_time =
+            Utility::to_local_time(tds[0].unwrap().text().collect::<String>().trim());
+        submission_info.language = tds[3]
+            .unwrap()
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
         submission_info.verdict_info = status;
         submission_info.execute_time = execute_time;
         submission_info.execute_memory = execute_memory;
@@ -66,8 +81,318 @@ impl HtmlParser {
             "WJ" => Verdict::Waiting,
             _ => Verdict::Resulted,
         };
+        submission_info.judge_test_cases = match Self::parse_judge_test_cases(&document) {
+            Ok(judge_test_cases) => judge_test_cases,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        submission_info.failed_test = submission_info
+            .judge_test_cases
+            .iter()
+            .find(|x| x.verdict_info != "AC")
+            .map(|x| x.name.clone());
         return Ok(submission_info);
     }
+
+    pub fn parse_submission_source(resp: &str) -> Result<SubmissionSource, String> {
+        let document = Html::parse_document(&resp);
+        let pre_selector = match Selector::parse("pre#submission-code") {
+            Ok(pre_selector) => pre_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let a_selector = match Selector::parse(r#"table a[href*="/tasks/"]"#) {
+            Ok(a_selector) => a_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let code = match document.select(&pre_selector).next() {
+            Some(pre) => pre.text().collect::<String>(),
+            None => {
+                return Err(String::from("Failed to find source code."));
+            }
+        };
+        let task = match document.select(&a_selector).next() {
+            Some(task) => task.text().collect::<String>(),
+            None => {
+                return Err(String::from("Failed to find task."));
+            }
+        };
+        let problem_identifier = match task.split_once(" - ") {
+            Some((problem_identifier, _)) => problem_identifier.trim().to_string(),
+            None => {
+                return Err(String::from("Failed to parse task."));
+            }
+        };
+        return Ok(SubmissionSource {
+            problem_identifier,
+            code,
+        });
+    }
+
+    /// Parse the table of judge test cases, which is shown after judging finished.
+    fn parse_judge_test_cases(document: &Html) -> Result<Vec<JudgeTestCase>, String> {
+        let table_selector = match Selector::parse("table") {
+            Ok(table_selector) => table_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let th_selector = match Selector::parse("thead th") {
+            Ok(th_selector) => th_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let tr_selector = match Selector::parse("tbody tr") {
+            Ok(tr_selector) => tr_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let table = document.select(&table_selector).find(|table| {
+            table
+                .select(&th_selector)
+                .any(|th| th.text().collect::<String>().trim() == "Case Name")
+        });
+        let table = match table {
+            Some(table) => table,
+            None => return Ok(Vec::new()),
+        };
+        let mut judge_test_cases = Vec::new();
+        for tr in table.select(&tr_selector) {
+            let tds = tr
+                .children()
+                .filter_map(ElementRef::wrap)
+                .filter(|x| x.value().name() == "td")
+                .map(|x| x.text().collect::<String>().trim().to_string())
+                .collect::<Vec<_>>();
+            if tds.len() != 4 {
+                continue;
+            }
+            judge_test_cases.push(JudgeTestCase {
+                name: tds[0].clone(),
+                verdict_info: tds[1].clone(),
+                execute_time: tds[2].clone(),
+                execute_memory: tds[3].clone(),
+            });
+        }
+        return Ok(judge_test_cases);
+    }
+    /// Parse submissions table of `/contests/{id}/submissions/me`.
+    pub fn parse_submission_list(resp: &str) -> Result<Vec<PostSubmissionInfo>, String> {
+        let document = Html::parse_document(resp);
+        let tr_selector = match Selector::parse("tbody tr") {
+            Ok(tr_selector) => tr_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let mut submissions = Vec::new();
+        for tr in document.select(&tr_selector) {
+            let tds = tr
+                .children()
+                .filter_map(ElementRef::wrap)
+                .filter(|x| x.value().name() == "td")
+                .collect::<Vec<_>>();
+            // Time and memory are merged into status column while waiting for judge.
+            if tds.len() != 10 && tds.len() != 8 {
+                continue;
+            }
+            let text = |index: usize| tds[index].text().collect::<String>().trim().to_string();
+            let mut submission_info = PostSubmissionInfo::new();
+            submission_info.submission_id = tds[4]
+                .value()
+                .attr("data-id")
+                .unwrap_or_default()
+                .to_string();
+            submission_info.submit_time = Utility::to_local_time(&text(0));
+            let task_href = tds[1]
+                .children()
+                .filter_map(ElementRef::wrap)
+                .find_map(|x| x.value().attr("href"))
+                .unwrap_or_default();
+            let vec = task_href.split('/').collect::<Vec<_>>();
+            if vec.len() == 5 {
+                submission_info.contest_identifier = vec[2].to_string();
+            }
+            submission_info.problem_identifier = match text(1).split_once(" - ") {
+                Some((problem_identifier, _)) => problem_identifier.to_string(),
+                None => text(1),
+            };
+            submission_info.language = text(3);
+            submission_info.verdict_info = text(6);
+            if tds.len() == 10 {
+                submission_info.execute_time = text(7);
+                submission_info.execute_memory = text(8);
+            }
+            submission_info.verdict = match submission_info.verdict_info.as_str() {
+                "Judging" => Verdict::Waiting,
+                "WJ" => Verdict::Waiting,
+                // Progress of judging, such as `3/20`.
+                status if status.contains('/') => Verdict::Waiting,
+                _ => Verdict::Resulted,
+            };
+            submissions.push(submission_info);
+        }
+        return Ok(submissions);
+    }
+    /// Parse standings from `/contests/{id}/standings/json`.
+    pub fn parse_standings(resp: &str, handles: &[String]) -> Result<Standings, String> {
+        let value = match serde_json::from_str::<serde_json::Value>(resp) {
+            Ok(value) => value,
+            Err(info) => {
+                return Err(format!("Failed to parse standings, {}", info));
+            }
+        };
+        let tasks = match value["TaskInfo"].as_array() {
+            Some(tasks) => tasks,
+            None => {
+                return Err(String::from("Failed to find tasks in standings."));
+            }
+        };
+        let rows = match value["StandingsData"].as_array() {
+            Some(rows) => rows,
+            None => {
+                return Err(String::from("Failed to find rows in standings."));
+            }
+        };
+        // Full points are not in standings, take the best score of all rows instead.
+        let problem_points = tasks
+            .iter()
+            .map(|task| {
+                let task_screen_name = task["TaskScreenName"].as_str().unwrap_or_default();
+                rows.iter()
+                    .map(|row| row["TaskResults"][task_screen_name]["Score"].as_u64())
+                    .map(|score| score.unwrap_or_default() / 100)
+                    .max()
+                    .unwrap_or_default() as f64
+            })
+            .collect::<Vec<_>>();
+        let mut standings_rows = Vec::new();
+        for row in rows {
+            let handle = row["UserScreenName"].as_str().unwrap_or_default();
+            if !handles.is_empty() && !handles.iter().any(|x| x.eq_ignore_ascii_case(handle)) {
+                continue;
+            }
+            let problem_results = tasks
+                .iter()
+                .map(|task| {
+                    let task_screen_name = task["TaskScreenName"].as_str().unwrap_or_default();
+                    let result = &row["TaskResults"][task_screen_name];
+                    // Scores are multiplied by 100 in standings.
+                    let score = result["Score"].as_u64().unwrap_or_default() / 100;
+                    let penalty = result["Penalty"].as_u64().unwrap_or_default();
+                    match (score > 0, penalty) {
+                        (true, 0) => score.to_string(),
+                        (true, _) => format!("{} ({})", score, penalty),
+                        (false, 0) => String::new(),
+                        (false, _) => format!("-{}", penalty),
+                    }
+                })
+                .collect::<Vec<_>>();
+            // Elapsed time is in nanoseconds, and each rejected submission adds 5 minutes.
+            let elapsed = row["TotalResult"]["Elapsed"].as_u64().unwrap_or_default()
+                / 1_000_000_000
+                + row["TotalResult"]["Penalty"].as_u64().unwrap_or_default() * 300;
+            standings_rows.push(StandingsRow {
+                rank: row["Rank"].as_u64().unwrap_or_default(),
+                handle: handle.to_string(),
+                score: (row["TotalResult"]["Score"].as_u64().unwrap_or_default() / 100).to_string(),
+                penalty: format!("{}:{:02}", elapsed / 60, elapsed % 60),
+                problem_results,
+            });
+        }
+        return Ok(Standings {
+            problems: tasks
+                .iter()
+                .map(|x| x["Assignment"].as_str().unwrap_or_default().to_string())
+                .collect(),
+            problem_points,
+            rule: ScoringRule::AtCoder,
+            rows: standings_rows,
+        });
+    }
+    /// Parse estimated difficulties from problem models of AtCoder Problems, keyed by task id.
+    /// Low difficulties are clipped the same way as AtCoder Problems shows them.
+    pub fn parse_problem_difficulties(
+        resp: &str,
+    ) -> Result<HashMap<String, ProblemDifficulty>, String> {
+        let value = match serde_json::from_str::<serde_json::Value>(resp) {
+            Ok(value) => value,
+            Err(info) => {
+                return Err(format!("Parse problem models failed, {}", info));
+            }
+        };
+        let models = match value.as_object() {
+            Some(models) => models,
+            None => {
+                return Err(String::from("Invalid problem models."));
+            }
+        };
+        let mut difficulties = HashMap::new();
+        for (task_id, model) in models {
+            let difficulty = match model["difficulty"].as_f64() {
+                Some(difficulty) => difficulty,
+                None => continue,
+            };
+            let difficulty = match difficulty >= 400.0 {
+                true => difficulty,
+                false => 400.0 / ((400.0 - difficulty) / 400.0).exp(),
+            };
+            difficulties.insert(
+                task_id.to_lowercase(),
+                ProblemDifficulty {
+                    difficulty: Some(difficulty.round() as i64),
+                    tags: Vec::new(),
+                },
+            );
+        }
+        return Ok(difficulties);
+    }
+
+    /// Parse result of `/contests/{id}/custom_test/json`.
+    pub fn parse_custom_test_result(resp: &str) -> Result<CustomTestResult, String> {
+        let value = match serde_json::from_str::<serde_json::Value>(resp) {
+            Ok(value) => value,
+            Err(info) => {
+                return Err(format!("Failed to parse custom test result, {}", info));
+            }
+        };
+        let status = value["Result"]["Status"].as_u64().unwrap_or_default();
+        // Status is 3 after the execution finished.
+        if status != 3 {
+            return Ok(CustomTestResult {
+                verdict: Verdict::Waiting,
+                stdout: String::new(),
+                stderr: String::new(),
+                exit_code: None,
+                execute_time: String::new(),
+                execute_memory: String::new(),
+            });
+        }
+        return Ok(CustomTestResult {
+            verdict: Verdict::Resulted,
+            stdout: value["Stdout"].as_str().unwrap_or_default().to_string(),
+            stderr: value["Stderr"].as_str().unwrap_or_default().to_string(),
+            exit_code: value["Result"]["ExitCode"].as_i64(),
+            execute_time: format!(
+                "{} ms",
+                value["Result"]["TimeConsumption"]
+                    .as_i64()
+                    .unwrap_or_default()
+            ),
+            execute_memory: format!(
+                "{} KB",
+                value["Result"]["MemoryConsumption"]
+                    .as_i64()
+                    .unwrap_or_default()
+            ),
+        });
+    }
     pub fn parse_problem_list(
         contest_identifier: &str,
         resp: &str,
@@ -181,6 +506,7 @@ impl HtmlParser {
             start_time,
             end_time,
             status: ContestStatus::NotStarted,
+            registration: String::new(),
         };
         if start_time > Utc::now() {
             contest.status = ContestStatus::NotStarted;
@@ -191,6 +517,129 @@ impl HtmlParser {
         }
         return Ok(contest);
     }
+
+    /// Parse active and upcoming contests of `/contests/`.
+    pub fn parse_contest_list(resp: &str) -> Result<Vec<Contest>, String> {
+        let document = Html::parse_document(&resp);
+        let tr_selector = match Selector::parse(
+            "#contest-table-action tbody tr, #contest-table-upcoming tbody tr",
+        ) {
+            Ok(tr_selector) => tr_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let mut contests = Vec::new();
+        for tr in document.select(&tr_selector) {
+            match Self::parse_contest_row(tr) {
+                Ok(contest) => contests.push(contest),
+                Err(info) => {
+                    log::warn!("Failed to parse contest row, {}", info);
+                }
+            }
+        }
+        return Ok(contests);
+    }
+
+    fn parse_contest_row(tr: ElementRef) -> Result<Contest, String> {
+        let td_selector = match Selector::parse("td") {
+            Ok(td_selector) => td_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let a_selector = match Selector::parse("a") {
+            Ok(a_selector) => a_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let tds = tr.select(&td_selector).collect::<Vec<_>>();
+        if tds.len() != 4 {
+            return Err(String::from("Failed to find tds."));
+        }
+        let start_time_href = match tds[0]
+            .select(&a_selector)
+            .next()
+            .and_then(|x| x.value().attr("href"))
+        {
+            Some(start_time_href) => start_time_href,
+            None => {
+                return Err(String::from("Failed to find start time."));
+            }
+        };
+        let start_time = match Utility::get_datetime_from_href(start_time_href) {
+            Ok(start_time) => start_time,
+            Err(info) => return Err(info),
+        };
+        let contest_anchor = match tds[1].select(&a_selector).next() {
+            Some(contest_anchor) => contest_anchor,
+            None => {
+                return Err(String::from("Failed to find contest."));
+            }
+        };
+        let identifier = match contest_anchor
+            .value()
+            .attr("href")
+            .and_then(|x| x.strip_prefix("/contests/"))
+        {
+            Some(identifier) => identifier.to_string(),
+            None => {
+                return Err(String::from("Failed to find contest identifier."));
+            }
+        };
+        let duration = match Utility::get_duration(tds[2].text().collect::<String>().trim()) {
+            Ok(duration) => duration,
+            Err(info) => return Err(info),
+        };
+        let end_time = start_time + duration;
+        let status = if start_time > Utc::now() {
+            ContestStatus::NotStarted
+        } else if end_time >= Utc::now() {
+            ContestStatus::Running
+        } else {
+            ContestStatus::Ended
+        };
+        return Ok(Contest {
+            identifier,
+            title: contest_anchor.text().collect::<String>().trim().to_string(),
+            start_time,
+            end_time,
+            status,
+            registration: String::new(),
+        });
+    }
+    /// Parse options of language select in submit page, every task has its own select.
+    /// # Returns
+    /// * `Ok(Vec<(String, String)>)` - Pairs of submit id and description.
+    pub fn parse_language_options(resp: &str) -> Result<Vec<(String, String)>, String> {
+        let document = Html::parse_document(resp);
+        let option_selector = match Selector::parse(r#"select[name="data.LanguageId"] option"#) {
+            Ok(option_selector) => option_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let mut options: Vec<(String, String)> = Vec::new();
+        for option in document.select(&option_selector) {
+            let id = match option.value().attr("value") {
+                Some(id) => id.trim(),
+                None => continue,
+            };
+            if id.is_empty() || options.iter().any(|(x, _)| x == id) {
+                continue;
+            }
+            let description = option.text().collect::<String>().trim().to_string();
+            options.push((String::from(id), description));
+        }
+        if options.is_empty() {
+            return Err(String::from(
+                "Failed to get language list, please check login status.",
+            ));
+        }
+        return Ok(options);
+    }
+
     pub fn parse_recent_submission_id(resp: &str) -> Result<String, String> {
         let document = Html::parse_document(resp);
         let td_selector = match Selector::parse(r#"td[class*="submission-score"]"#) {
@@ -277,6 +726,97 @@ impl HtmlParser {
         }
         return Ok(sample_vec);
     }
+
+    pub fn parse_problem_metadata(resp: &str) -> Result<ProblemMetadata, String> {
+        let document = Html::parse_document(resp);
+        let p_selector = match Selector::parse("p") {
+            Ok(p_selector) => p_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let mut metadata = ProblemMetadata::default();
+        let limit = document
+            .select(&p_selector)
+            .map(|x| x.text().collect::<String>())
+            .find(|x| x.contains("Time Limit") || x.contains("実行時間制限"));
+        if let Some(limit) = limit {
+            let (time_limit, memory_limit) = match Utility::get_limits(&limit) {
+                Ok(limits) => limits,
+                Err(info) => {
+                    return Err(info);
+                }
+            };
+            metadata.time_limit = Some(time_limit);
+            metadata.memory_limit = Some(memory_limit);
+        }
+        if let Ok(statement) = Self::parse_statement(resp) {
+            metadata.interactive = ProblemType::is_interactive(&statement);
+            metadata.special_judge = ProblemType::is_special_judge(&statement);
+            metadata.error_tolerance = ProblemType::get_error_tolerance(&statement);
+        }
+        return Ok(metadata);
+    }
+
+    pub fn parse_statement(resp: &str) -> Result<String, String> {
+        let document = Html::parse_document(resp);
+        let title_selector = match Selector::parse("span.h2") {
+            Ok(title_selector) => title_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let title = match document.select(&title_selector).next() {
+            Some(title) => title
+                .children()
+                .filter_map(|x| x.value().as_text())
+                .map(|x| x.to_string())
+                .collect::<String>(),
+            None => {
+                return Err(String::from("Failed to find title."));
+            }
+        };
+        let p_selector = match Selector::parse("p") {
+            Ok(p_selector) => p_selector,
+            Err(info) => {
+                return Err(format!("Failed to parse selector, {}", info));
+            }
+        };
+        let limit = document
+            .select(&p_selector)
+            .map(|x| x.text().collect::<String>())
+            .find(|x| x.contains("Time Limit"))
+            .unwrap_or_default();
+        let mut statement = None;
+        for selector_str in [
+            "#task-statement span.lang-en",
+            "#task-statement span.lang-ja",
+            "#task-statement",
+        ] {
+            let statement_selector = match Selector::parse(selector_str) {
+                Ok(statement_selector) => statement_selector,
+                Err(info) => {
+                    return Err(format!("Failed to parse selector, {}", info));
+                }
+            };
+            statement = document.select(&statement_selector).next();
+            if statement.is_some() {
+                break;
+            }
+        }
+        let statement = match statement {
+            Some(statement) => statement,
+            None => {
+                return Err(String::from("Failed to find task statement."));
+            }
+        };
+        return Ok(format!(
+            "# {}\n\n{}\n\n{}",
+            title.trim(),
+            limit.trim(),
+            Markdown::from_html(statement)
+        ));
+    }
 }
 
 #[test]
@@ -288,8 +828,65 @@ fn test_parse_submission_page() {
     assert_eq!(submission_info.contest_identifier, "abc321");
     assert_eq!(submission_info.problem_identifier, "b");
     assert_eq!(submission_info.verdict_info, "WA");
+    assert_eq!(submission_info.judge_test_cases.len(), 46);
+    assert_eq!(
+        submission_info.failed_test,
+        Some(String::from("test_10.txt"))
+    );
+    assert_eq!(submission_info.judge_test_cases[13].name, "test_10.txt");
+    assert_eq!(submission_info.language, "C++ 20 (gcc 12.2)");
+}
+#[test]
+fn test_parse_submission_source() {
+    let content = std::fs::read_to_string("assets/atcoder/submission_page.html").unwrap();
+    let source = HtmlParser::parse_submission_source(&content).unwrap();
+    assert_eq!(source.problem_identifier, "B");
+    assert!(source.code.starts_with("#include <bits/stdc++.h>"));
 }
 
+#[test]
+fn test_parse_submission_list() {
+    let content = std::fs::read_to_string("assets/atcoder/recent_submission.html").unwrap();
+    let submissions = HtmlParser::parse_submission_list(&content).unwrap();
+    assert_eq!(submissions.len(), 2);
+    assert_eq!(submissions[0].submission_id, "46003634");
+    assert_eq!(submissions[0].contest_identifier, "arc165");
+    assert_eq!(submissions[0].problem_identifier, "B");
+    assert_eq!(submissions[0].verdict, Verdict::Waiting);
+    assert_eq!(submissions[0].language, "C++ 20 (gcc 12.2)");
+    assert_eq!(submissions[1].verdict, Verdict::Resulted);
+    assert_eq!(submissions[1].verdict_info, "WA");
+    assert_eq!(submissions[1].execute_time, "1 ms");
+    assert_eq!(submissions[1].execute_memory, "3588 KB");
+}
+#[test]
+fn test_parse_standings() {
+    let content = std::fs::read_to_string("assets/atcoder/standings.json").unwrap();
+    let standings = HtmlParser::parse_standings(&content, &[]).unwrap();
+    assert_eq!(standings.problems, vec!["A", "B", "C"]);
+    assert_eq!(standings.rule, ScoringRule::AtCoder);
+    assert_eq!(standings.problem_points, vec![100.0, 200.0, 300.0]);
+    assert_eq!(standings.rows.len(), 2);
+    let standings = HtmlParser::parse_standings(&content, &[String::from("dianhsu")]).unwrap();
+    assert_eq!(standings.rows.len(), 1);
+    assert_eq!(standings.rows[0].rank, 2048);
+    assert_eq!(standings.rows[0].score, "300");
+    assert_eq!(standings.rows[0].penalty, "45:00");
+    assert_eq!(
+        standings.rows[0].problem_results,
+        vec!["100", "200 (2)", "-1"]
+    );
+}
+#[test]
+fn test_parse_custom_test_result() {
+    let content = std::fs::read_to_string("assets/atcoder/custom_test.json").unwrap();
+    let result = HtmlParser::parse_custom_test_result(&content).unwrap();
+    assert_eq!(result.verdict, Verdict::Resulted);
+    assert_eq!(result.stdout, "3\n");
+    assert_eq!(result.exit_code, Some(0));
+    assert_eq!(result.execute_time, "2 ms");
+    assert_eq!(result.execute_memory, "3612 KB");
+}
 #[test]
 fn test_parse_problem_list() {
     let content = std::fs::read_to_string("assets/atcoder/problem_list.html").unwrap();
@@ -305,6 +902,22 @@ fn test_parse_contest() {
     assert_eq!(contest.title.contains("AtCoder Beginner Contest 321"), true);
 }
 
+#[test]
+fn test_parse_contest_list() {
+    let content = std::fs::read_to_string("assets/atcoder/contest_list.html").unwrap();
+    let contests = HtmlParser::parse_contest_list(&content).unwrap();
+    assert_eq!(contests.len(), 3);
+    assert_eq!(contests[0].identifier, "ahc025");
+    assert_eq!(
+        contests[0].end_time - contests[0].start_time,
+        chrono::Duration::hours(240)
+    );
+    assert_eq!(contests[1].title, "AtCoder Beginner Contest 323");
+    // A malformed row is skipped instead of failing the whole list.
+    let content = content.replacen("<tbody>", "<tbody><tr><td>-</td></tr>", 1);
+    let contests = HtmlParser::parse_contest_list(&content).unwrap();
+    assert_eq!(contests.len(), 3);
+}
 #[test]
 fn test_parse_recent_submission_id() {
     let content = std::fs::read_to_string("assets/atcoder/recent_submission.html").unwrap();
@@ -318,3 +931,46 @@ fn test_parse_test_cases() {
     let test_cases = HtmlParser::parse_test_cases(&content).unwrap();
     println!("{:?}", test_cases)
 }
+
+#[test]
+fn test_parse_statement() {
+    let content = std::fs::read_to_string("assets/atcoder/test_cases.html").unwrap();
+    let statement = HtmlParser::parse_statement(&content).unwrap();
+    assert!(statement.starts_with("# D - Set Menu"));
+    assert!(statement.contains("Time Limit: 2 sec / Memory Limit: 1024 MB"));
+    assert!(statement.contains("### Problem Statement"));
+    assert!(statement.contains("$A_i$"));
+}
+
+#[test]
+fn test_parse_problem_metadata() {
+    let content = std::fs::read_to_string("assets/atcoder/test_cases.html").unwrap();
+    let metadata = HtmlParser::parse_problem_metadata(&content).unwrap();
+    assert_eq!(metadata.time_limit, Some(2000));
+    assert_eq!(metadata.memory_limit, Some(1024));
+    assert_eq!(metadata.interactive, false);
+    assert_eq!(metadata.special_judge, false);
+}
+
+#[test]
+fn test_parse_language_options() {
+    let content = std::fs::read_to_string("assets/atcoder/submit_languages.html").unwrap();
+    let options = HtmlParser::parse_language_options(&content).unwrap();
+    assert_eq!(options.len(), 8);
+    assert_eq!(
+        options[0],
+        (String::from("5001"), String::from("C++ 20 (gcc 12.2)"))
+    );
+    assert_eq!(options[7].0, "5100");
+}
+
+#[test]
+fn test_parse_problem_difficulties() {
+    let content = std::fs::read_to_string("assets/atcoder/problem_models.json").unwrap();
+    let difficulties = HtmlParser::parse_problem_difficulties(&content).unwrap();
+    assert_eq!(difficulties.len(), 4);
+    assert_eq!(difficulties["abc300_a"].difficulty, Some(10));
+    assert_eq!(difficulties["abc300_b"].difficulty, Some(264));
+    assert_eq!(difficulties["abc300_d"].difficulty, Some(836));
+    assert!(!difficulties.contains_key("abc300_h"));
+}
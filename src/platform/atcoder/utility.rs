@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc, NaiveTime, NaiveDate, FixedOffset, TimeZone};
+use chrono::{DateTime, Duration, Utc, NaiveTime, NaiveDate, FixedOffset, Local, TimeZone};
 
 pub struct Utility {}
 
@@ -43,6 +43,37 @@ impl Utility {
         let parsed_time = Utc.from_utc_datetime(&dt_with_tz.naive_utc());
         return Ok(parsed_time);
     }
+    /// Convert time like `2023-09-28 15:35:18+0900` to local time, keep it as-is if failed.
+    pub fn to_local_time(text: &str) -> String {
+        return match DateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%z") {
+            Ok(datetime) => datetime
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string(),
+            Err(_) => text.to_string(),
+        };
+    }
+    /// Parse duration like `01:40` or `240:00`.
+    pub fn get_duration(text: &str) -> Result<Duration, String> {
+        let (hours, minutes) = match text.split_once(':') {
+            Some(duration) => duration,
+            None => {
+                return Err(String::from("Failed to find duration."));
+            }
+        };
+        return match (hours.parse::<i64>(), minutes.parse::<i64>()) {
+            (Ok(hours), Ok(minutes)) => Ok(Duration::hours(hours) + Duration::minutes(minutes)),
+            _ => Err(format!("Failed to parse duration {}.", text)),
+        };
+    }
+    /// Handle in the script of pages, which is empty if it's not logged in.
+    pub fn get_login_handle(resp: &str) -> Option<String> {
+        let re = match regex::Regex::new(r#"var userScreenName = "([^"]+)""#) {
+            Ok(re) => re,
+            Err(_) => return None,
+        };
+        return re.captures(resp).map(|caps| caps[1].to_string());
+    }
     pub fn get_csrf(resp: &str) -> Result<String, String> {
         let re = match regex::Regex::new(r#"var csrfToken = "([\S]+)""#) {
             Ok(re) => re,
@@ -58,4 +89,46 @@ impl Utility {
         };
         return Ok(String::from(&caps[1]));
     }
+
+    /// Parse limits like `Time Limit: 2 sec / Memory Limit: 1024 MB`.
+    /// # Returns
+    /// * `Ok((u64, u64))` - Time limit in milliseconds and memory limit in megabytes.
+    pub fn get_limits(text: &str) -> Result<(u64, u64), String> {
+        let re = match regex::Regex::new(r#"([\d.]+)\s*sec\s*/[^\d]*(\d+)\s*MB"#) {
+            Ok(re) => re,
+            Err(_) => return Err(String::from("Failed to create regex.")),
+        };
+        let caps = match re.captures(text) {
+            Some(caps) => caps,
+            None => {
+                return Err(String::from("Failed to find limits."));
+            }
+        };
+        let seconds = match caps[1].parse::<f64>() {
+            Ok(seconds) => seconds,
+            Err(info) => {
+                return Err(format!("Failed to parse time limit, {}", info));
+            }
+        };
+        let megabytes = match caps[2].parse::<u64>() {
+            Ok(megabytes) => megabytes,
+            Err(info) => {
+                return Err(format!("Failed to parse memory limit, {}", info));
+            }
+        };
+        return Ok(((seconds * 1000.0).round() as u64, megabytes));
+    }
+}
+
+#[test]
+fn test_get_login_handle() {
+    let content = std::fs::read_to_string("assets/atcoder/contest.html").unwrap();
+    assert_eq!(
+        Utility::get_login_handle(&content),
+        Some(String::from("dianhsu"))
+    );
+    assert_eq!(
+        Utility::get_login_handle(r#"var userScreenName = "";"#),
+        None
+    );
 }
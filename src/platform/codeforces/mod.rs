@@ -3,9 +3,17 @@ mod constants;
 mod parser;
 mod utility;
 use crate::model::Platform;
+use crate::model::Contest;
+use crate::model::CustomTestResult;
+use crate::model::HackInfo;
 use crate::model::PlatformLanguage;
 use crate::model::PostSubmissionInfo;
+use crate::model::ProblemDifficulty;
+use crate::model::ProblemMetadata;
+use crate::model::Standings;
+use crate::model::SubmissionSource;
 use crate::model::TestCase;
+use crate::utility::language::LanguageUtility;
 use cbc::cipher::{BlockDecryptMut, KeyIvInit};
 use regex::Regex;
 
@@ -24,14 +32,15 @@ impl OnlineJudgeBehavior for Codeforces {
     }
 
     fn check_login(resp: &str) -> bool {
-        let re = match Regex::new(r#"handle = "([\s\S]+?)""#) {
-            Ok(re) => re,
-            Err(_) => return false,
-        };
-        match re.captures(resp) {
-            Some(_) => true,
-            None => false,
-        }
+        return Self::get_login_handle(resp).is_some();
+    }
+
+    fn get_login_handle(resp: &str) -> Option<String> {
+        return Utility::get_login_handle(resp);
+    }
+
+    fn get_session_cookie_name() -> String {
+        return String::from("X-User-Sha1");
     }
 
     fn build_login_page_url() -> String {
@@ -61,10 +70,110 @@ impl OnlineJudgeBehavior for Codeforces {
         return data;
     }
 
+    fn build_custom_test_page_url(contest_identifier: &str) -> String {
+        return format!(
+            "https://codeforces.com/contest/{}/customtest",
+            contest_identifier
+        );
+    }
+
+    fn build_custom_test_url(_contest_identifier: &str) -> String {
+        return String::from("https://codeforces.com/data/customtest");
+    }
+
+    fn build_custom_test_request_data(
+        code: &str,
+        lang_id: &str,
+        input: &str,
+        csrf_token: &str,
+    ) -> HashMap<String, String> {
+        let mut data = HashMap::new();
+        data.insert("csrf_token".to_string(), csrf_token.to_string());
+        data.insert("action".to_string(), "submitSourceCode".to_string());
+        data.insert("programTypeId".to_string(), lang_id.to_string());
+        data.insert("source".to_string(), code.to_string());
+        data.insert("tabSize".to_string(), "4".to_string());
+        data.insert("input".to_string(), input.to_string());
+        return data;
+    }
+
+    fn build_custom_test_result_url(_contest_identifier: &str) -> String {
+        return String::from("https://codeforces.com/data/customtest");
+    }
+
+    fn build_custom_test_result_request_data(
+        custom_test_id: &str,
+        csrf_token: &str,
+    ) -> Option<HashMap<String, String>> {
+        let mut data = HashMap::new();
+        data.insert("csrf_token".to_string(), csrf_token.to_string());
+        data.insert("action".to_string(), "getVerdict".to_string());
+        data.insert("customTestSubmitId".to_string(), custom_test_id.to_string());
+        return Some(data);
+    }
+
+    fn build_hack_url(contest_identifier: &str) -> Option<String> {
+        return Some(format!(
+            "https://codeforces.com/contest/{}/challenge",
+            contest_identifier
+        ));
+    }
+
+    fn build_hack_list_url(contest_identifier: &str) -> String {
+        return format!(
+            "https://codeforces.com/contest/{}/hacks",
+            contest_identifier
+        );
+    }
+
+    fn build_hack_request_data(
+        submission_id: &str,
+        input: &str,
+        generator: Option<(&str, &str)>,
+        csrf_token: &str,
+    ) -> HashMap<String, String> {
+        let mut data = HashMap::new();
+        data.insert("csrf_token".to_string(), csrf_token.to_string());
+        data.insert("action".to_string(), "challengeFormSubmitted".to_string());
+        data.insert("submissionId".to_string(), submission_id.to_string());
+        data.insert("testcase".to_string(), input.to_string());
+        match generator {
+            Some((code, lang_id)) => {
+                data.insert("inputType".to_string(), "generated".to_string());
+                data.insert("generatorSource".to_string(), code.to_string());
+                data.insert("programTypeId".to_string(), lang_id.to_string());
+            }
+            None => {
+                data.insert("inputType".to_string(), "manual".to_string());
+            }
+        }
+        return data;
+    }
+
     fn build_contest_url(contest_identifier: &str) -> String {
         return format!("https://codeforces.com/contests/{}", contest_identifier);
     }
 
+    fn build_contest_list_url() -> String {
+        return String::from("https://codeforces.com/contests");
+    }
+
+    fn build_language_list_url() -> String {
+        return String::from("https://codeforces.com/problemset/submit");
+    }
+
+    fn build_problem_url(contest_identifier: &str, problem_identifier: &str) -> String {
+        return format!(
+            "https://codeforces.com/contest/{}/problem/{}",
+            contest_identifier,
+            problem_identifier.to_uppercase()
+        );
+    }
+
+    fn build_problem_difficulty_url() -> String {
+        return String::from("https://codeforces.com/api/problemset.problems");
+    }
+
     fn build_problem_list_url(contest_identifier: &str) -> String {
         return format!("https://codeforces.com/contest/{}", contest_identifier);
     }
@@ -90,6 +199,50 @@ impl OnlineJudgeBehavior for Codeforces {
         );
     }
 
+    fn build_submission_list_url(contest_identifier: &str) -> String {
+        return format!("https://codeforces.com/contest/{}/my", contest_identifier);
+    }
+
+    fn build_accepted_submission_list_url(
+        contest_identifier: &str,
+        username: &str,
+        page: usize,
+    ) -> Option<String> {
+        // The API returns all submissions of the handle at once.
+        if page > 1 {
+            return None;
+        }
+        return Some(format!(
+            "https://codeforces.com/api/contest.status?contestId={}&handle={}",
+            contest_identifier, username
+        ));
+    }
+
+    fn build_standings_url(contest_identifier: &str, handles: &[String]) -> String {
+        if handles.is_empty() {
+            return format!(
+                "https://codeforces.com/api/contest.standings?contestId={}&from=1&count=50",
+                contest_identifier
+            );
+        }
+        return format!(
+            "https://codeforces.com/api/contest.standings?contestId={}&handles={}&showUnofficial=true",
+            contest_identifier,
+            handles.join(";")
+        );
+    }
+
+    fn build_full_standings_url(contest_identifier: &str) -> String {
+        return format!(
+            "https://codeforces.com/api/contest.standings?contestId={}",
+            contest_identifier
+        );
+    }
+
+    fn build_user_submission_list_url(username: &str) -> Option<String> {
+        return Some(format!("https://codeforces.com/submissions/{}", username));
+    }
+
     fn parse_problem_list(
         contest_identifier: &str,
         resp: &str,
@@ -101,6 +254,24 @@ impl OnlineJudgeBehavior for Codeforces {
         return HtmlParser::parse_test_cases(resp);
     }
 
+    fn parse_statement(resp: &str) -> Result<String, String> {
+        return HtmlParser::parse_statement(resp);
+    }
+
+    fn parse_problem_metadata(resp: &str) -> Result<ProblemMetadata, String> {
+        return HtmlParser::parse_problem_metadata(resp);
+    }
+
+    fn parse_problem_difficulties(
+        resp: &str,
+    ) -> Result<HashMap<String, ProblemDifficulty>, String> {
+        return HtmlParser::parse_problem_difficulties(resp);
+    }
+
+    fn get_problem_difficulty_key(full_problem_identifier: &str, _problem_url: &str) -> String {
+        return full_problem_identifier.to_lowercase();
+    }
+
     fn parse_submission_page(
         contest_identifier: &str,
         problem_identifier: &str,
@@ -119,11 +290,58 @@ impl OnlineJudgeBehavior for Codeforces {
         return HtmlParser::parse_recent_submission_id(resp);
     }
 
+    fn parse_submission_source(resp: &str) -> Result<SubmissionSource, String> {
+        return HtmlParser::parse_submission_source(resp);
+    }
+
+    fn parse_submission_list(resp: &str) -> Result<Vec<PostSubmissionInfo>, String> {
+        return HtmlParser::parse_submission_list(resp);
+    }
+
+    fn parse_accepted_submission_list(resp: &str) -> Result<Vec<PostSubmissionInfo>, String> {
+        return HtmlParser::parse_accepted_submission_list(resp);
+    }
+
 
     fn parse_contest(contest_identifier: &str, resp: &str) -> Result<crate::model::Contest, String> {
         return HtmlParser::parse_contest(contest_identifier, resp);
     }
 
+    fn parse_standings(resp: &str, handles: &[String]) -> Result<Standings, String> {
+        return HtmlParser::parse_standings(resp, handles);
+    }
+
+    fn parse_platform_languages(resp: &str) -> Result<Vec<PlatformLanguage>, String> {
+        return match HtmlParser::parse_language_options(resp) {
+            Ok(options) => Ok(LanguageUtility::match_platform_languages(
+                Platform::Codeforces,
+                options,
+                &Self::get_platform_languages(),
+            )),
+            Err(info) => Err(info),
+        };
+    }
+
+    fn parse_hack_list(resp: &str) -> Result<Vec<HackInfo>, String> {
+        return HtmlParser::parse_hack_list(resp);
+    }
+
+    fn parse_hack_result(resp: &str) -> Result<(), String> {
+        return HtmlParser::parse_form_errors(resp);
+    }
+
+    fn parse_contest_list(resp: &str) -> Result<Vec<Contest>, String> {
+        return HtmlParser::parse_contest_list(resp);
+    }
+
+    fn parse_custom_test_id(resp: &str) -> Result<String, String> {
+        return HtmlParser::parse_custom_test_id(resp);
+    }
+
+    fn parse_custom_test_result(resp: &str) -> Result<CustomTestResult, String> {
+        return HtmlParser::parse_custom_test_result(resp);
+    }
+
     fn get_platform_languages() -> Vec<PlatformLanguage> {
         let mut vec = Vec::new();
         for (id, description, language) in constants::LANG.iter() {
@@ -1,13 +1,29 @@
+use std::collections::HashMap;
+
+use chrono::Local;
+use chrono::LocalResult;
+use chrono::TimeZone;
 use chrono::Utc;
 use regex::Regex;
+use scraper::ElementRef;
 use scraper::Html;
 use scraper::Selector;
 
 use crate::model::Contest;
 use crate::model::ContestStatus;
+use crate::model::CustomTestResult;
+use crate::model::HackInfo;
 use crate::model::PostSubmissionInfo;
+use crate::model::ProblemDifficulty;
+use crate::model::ProblemMetadata;
+use crate::model::ScoringRule;
+use crate::model::Standings;
+use crate::model::StandingsRow;
+use crate::model::SubmissionSource;
 use crate::model::TestCase;
 use crate::model::Verdict;
+use crate::utility::markdown::Markdown;
+use crate::utility::problem_type::ProblemType;
 
 use super::utility::Utility;
 
@@ -30,6 +46,32 @@ impl HtmlParser {
                 return Err(String::from("Select tr failed."));
             }
         };
+        return Self::parse_contest_row(contest_identifier, tr);
+    }
+
+    /// Parse contests in upcoming and past tables of `/contests`.
+    pub fn parse_contest_list(resp: &str) -> Result<Vec<Contest>, String> {
+        let document = Html::parse_document(&resp);
+        let tr_selector = match Selector::parse("table tr[data-contestid]") {
+            Ok(tr_selector) => tr_selector,
+            Err(_) => {
+                return Err(String::from("Build tr selector failed."));
+            }
+        };
+        let mut contests = Vec::new();
+        for tr in document.select(&tr_selector) {
+            let contest_identifier = tr.value().attr("data-contestid").unwrap_or_default();
+            match Self::parse_contest_row(contest_identifier, tr) {
+                Ok(contest) => contests.push(contest),
+                Err(info) => {
+                    log::warn!("Parse contest {} failed: {}", contest_identifier, info);
+                }
+            }
+        }
+        return Ok(contests);
+    }
+
+    fn parse_contest_row(contest_identifier: &str, tr: ElementRef) -> Result<Contest, String> {
         let td_selector = match Selector::parse("td") {
             Ok(td_selector) => td_selector,
             Err(_) => {
@@ -88,6 +130,7 @@ impl HtmlParser {
             start_time,
             end_time,
             status: ContestStatus::NotStarted,
+            registration: Self::get_registration(tds[5]),
         };
         if start_time > Utc::now() {
             contest.status = ContestStatus::NotStarted;
@@ -98,6 +141,228 @@ impl HtmlParser {
         }
         return Ok(contest);
     }
+
+    /// Get registration state such as `Register`, `Registration completed` and `Registration closed`.
+    fn get_registration(td: ElementRef) -> String {
+        let text = td
+            .children()
+            .filter_map(|x| match x.value().as_text() {
+                Some(text) => Some(text.to_string()),
+                None => ElementRef::wrap(x)
+                    .filter(|x| {
+                        !x.value()
+                            .classes()
+                            .any(|x| x == "contestParticipantCountLinkMargin")
+                    })
+                    .map(|x| x.text().collect::<String>()),
+            })
+            .collect::<String>();
+        return text
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .trim_end_matches('»')
+            .trim()
+            .to_string();
+    }
+    /// Parse standings from `contest.standings` api.
+    pub fn parse_standings(resp: &str, handles: &[String]) -> Result<Standings, String> {
+        let value = match serde_json::from_str::<serde_json::Value>(resp) {
+            Ok(value) => value,
+            Err(info) => {
+                return Err(format!("Parse standings failed, {}", info));
+            }
+        };
+        if value["status"] != "OK" {
+            return Err(format!(
+                "Get standings failed, {}",
+                value["comment"].as_str().unwrap_or("unknown error")
+            ));
+        }
+        let result = &value["result"];
+        let is_icpc = result["contest"]["type"] == "ICPC";
+        let problems = match result["problems"].as_array() {
+            Some(problems) => problems,
+            None => {
+                return Err(String::from("Problems not found in standings."));
+            }
+        };
+        let problem_points = problems
+            .iter()
+            .map(|x| match is_icpc {
+                true => 1.0,
+                false => x["points"].as_f64().unwrap_or_default(),
+            })
+            .collect::<Vec<_>>();
+        let problems = problems
+            .iter()
+            .map(|x| x["index"].as_str().unwrap_or_default().to_string())
+            .collect::<Vec<_>>();
+        let rows = match result["rows"].as_array() {
+            Some(rows) => rows,
+            None => {
+                return Err(String::from("Rows not found in standings."));
+            }
+        };
+        let mut standings_rows = Vec::new();
+        for row in rows {
+            let members = match row["party"]["members"].as_array() {
+                Some(members) => members
+                    .iter()
+                    .filter_map(|x| x["handle"].as_str())
+                    .collect::<Vec<_>>(),
+                None => Vec::new(),
+            };
+            if !handles.is_empty()
+                && !members
+                    .iter()
+                    .any(|x| handles.iter().any(|y| y.eq_ignore_ascii_case(x)))
+            {
+                continue;
+            }
+            let handle = match row["party"]["teamName"].as_str() {
+                Some(team_name) => team_name.to_string(),
+                None => members.join(", "),
+            };
+            let problem_results = match row["problemResults"].as_array() {
+                Some(problem_results) => problem_results
+                    .iter()
+                    .map(|x| {
+                        let points = x["points"].as_f64().unwrap_or_default();
+                        let rejected = x["rejectedAttemptCount"].as_u64().unwrap_or_default();
+                        match (points > 0.0, is_icpc, rejected) {
+                            (true, true, 0) => String::from("+"),
+                            (true, true, _) => format!("+{}", rejected),
+                            (true, false, _) => format!("{}", points),
+                            (false, _, 0) => String::new(),
+                            (false, _, _) => format!("-{}", rejected),
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+                None => Vec::new(),
+            };
+            standings_rows.push(StandingsRow {
+                rank: row["rank"].as_u64().unwrap_or_default(),
+                handle,
+                score: format!("{}", row["points"].as_f64().unwrap_or_default()),
+                penalty: row["penalty"].as_u64().unwrap_or_default().to_string(),
+                problem_results,
+            });
+        }
+        return Ok(Standings {
+            problems,
+            problem_points,
+            rule: match is_icpc {
+                true => ScoringRule::Icpc,
+                false => ScoringRule::Codeforces,
+            },
+            rows: standings_rows,
+        });
+    }
+    /// Parse ratings and tags from `problemset.problems`, keyed by lowercase full problem identifier.
+    pub fn parse_problem_difficulties(
+        resp: &str,
+    ) -> Result<HashMap<String, ProblemDifficulty>, String> {
+        let value = match serde_json::from_str::<serde_json::Value>(resp) {
+            Ok(value) => value,
+            Err(info) => {
+                return Err(format!("Parse problemset failed, {}", info));
+            }
+        };
+        if value["status"] != "OK" {
+            return Err(format!(
+                "Get problemset failed, {}",
+                value["comment"].as_str().unwrap_or("unknown error")
+            ));
+        }
+        let problems = match value["result"]["problems"].as_array() {
+            Some(problems) => problems,
+            None => {
+                return Err(String::from("Problems not found in problemset."));
+            }
+        };
+        let mut difficulties = HashMap::new();
+        for problem in problems {
+            let contest_id = match problem["contestId"].as_u64() {
+                Some(contest_id) => contest_id,
+                None => continue,
+            };
+            let index = problem["index"].as_str().unwrap_or_default();
+            let tags = match problem["tags"].as_array() {
+                Some(tags) => tags
+                    .iter()
+                    .filter_map(|x| x.as_str())
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>(),
+                None => Vec::new(),
+            };
+            difficulties.insert(
+                format!("{}_{}", contest_id, index).to_lowercase(),
+                ProblemDifficulty {
+                    difficulty: problem["rating"].as_i64(),
+                    tags,
+                },
+            );
+        }
+        return Ok(difficulties);
+    }
+
+    pub fn parse_custom_test_id(resp: &str) -> Result<String, String> {
+        let value = match serde_json::from_str::<serde_json::Value>(resp) {
+            Ok(value) => value,
+            Err(info) => {
+                return Err(format!("Parse custom test response failed, {}", info));
+            }
+        };
+        return match &value["customTestSubmitId"] {
+            serde_json::Value::String(id) => Ok(id.to_string()),
+            serde_json::Value::Number(id) => Ok(id.to_string()),
+            _ => Err(format!("Submit custom test failed, {}", value)),
+        };
+    }
+    /// Parse result of `getVerdict` of custom invocation, `stat` is like `Used: 15 ms, 8 KB`
+    /// and `Exit code is 0`, it's missing until the code is run.
+    pub fn parse_custom_test_result(resp: &str) -> Result<CustomTestResult, String> {
+        let value = match serde_json::from_str::<serde_json::Value>(resp) {
+            Ok(value) => value,
+            Err(info) => {
+                return Err(format!("Parse custom test result failed, {}", info));
+            }
+        };
+        let mut result = CustomTestResult {
+            verdict: Verdict::Waiting,
+            stdout: String::new(),
+            stderr: String::new(),
+            exit_code: None,
+            execute_time: String::new(),
+            execute_memory: String::new(),
+        };
+        let stat = match value["stat"].as_str() {
+            Some(stat) => stat,
+            None => return Ok(result),
+        };
+        let stat_re = match Regex::new(r"(\d+)\s*ms,\s*(\d+)\s*KB") {
+            Ok(re) => re,
+            Err(_) => return Err(String::from("Create regex failed.")),
+        };
+        let exit_code_re = match Regex::new(r"Exit code is (-?\d+)") {
+            Ok(re) => re,
+            Err(_) => return Err(String::from("Create regex failed.")),
+        };
+        if let Some(caps) = stat_re.captures(stat) {
+            result.execute_time = format!("{} ms", &caps[1]);
+            result.execute_memory = format!("{} KB", &caps[2]);
+        }
+        if let Some(caps) = exit_code_re.captures(stat) {
+            result.exit_code = caps[1].parse::<i64>().ok();
+        }
+        result.verdict = Verdict::Resulted;
+        result.stdout = value["output"]
+            .as_str()
+            .unwrap_or_default()
+            .replace("\r\n", "\n");
+        return Ok(result);
+    }
     pub fn parse_problem_list(
         contest_identifier: &str,
         resp: &str,
@@ -156,6 +421,239 @@ impl HtmlParser {
         };
         return Ok(caps[1].to_string());
     }
+    /// Parse options of language select in submit page.
+    /// # Returns
+    /// * `Ok(Vec<(String, String)>)` - Pairs of submit id and description.
+    pub fn parse_language_options(resp: &str) -> Result<Vec<(String, String)>, String> {
+        let document = Html::parse_document(resp);
+        let option_selector = match Selector::parse(r#"select[name="programTypeId"] option"#) {
+            Ok(option_selector) => option_selector,
+            Err(_) => {
+                return Err(String::from("Build option selector failed."));
+            }
+        };
+        let mut options = Vec::new();
+        for option in document.select(&option_selector) {
+            let id = match option.value().attr("value") {
+                Some(id) => id.trim(),
+                None => continue,
+            };
+            if id.is_empty() {
+                continue;
+            }
+            let description = option.text().collect::<String>().trim().to_string();
+            options.push((String::from(id), description));
+        }
+        if options.is_empty() {
+            return Err(String::from(
+                "Can't find language list, please check login status.",
+            ));
+        }
+        return Ok(options);
+    }
+    /// Parse errors of a posted form, which is shown again with errors under its fields.
+    /// Fields without errors have hidden empty error spans.
+    pub fn parse_form_errors(resp: &str) -> Result<(), String> {
+        let document = Html::parse_document(resp);
+        let span_selector = match Selector::parse("span.error") {
+            Ok(span_selector) => span_selector,
+            Err(_) => {
+                return Err(String::from("Build span selector failed."));
+            }
+        };
+        let errors = document
+            .select(&span_selector)
+            .map(|x| x.text().collect::<String>().replace('\u{a0}', " "))
+            .map(|x| x.trim().to_string())
+            .filter(|x| !x.is_empty())
+            .collect::<Vec<_>>();
+        return match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors.join(" ")),
+        };
+    }
+    /// Parse hacks table of `/contest/{id}/hacks`.
+    pub fn parse_hack_list(resp: &str) -> Result<Vec<HackInfo>, String> {
+        let document = Html::parse_document(resp);
+        let tr_selector = match Selector::parse("tr[challengeid]") {
+            Ok(tr_selector) => tr_selector,
+            Err(_) => {
+                return Err(String::from("Build tr selector failed."));
+            }
+        };
+        let td_selector = match Selector::parse("td") {
+            Ok(td_selector) => td_selector,
+            Err(_) => {
+                return Err(String::from("Build td selector failed."));
+            }
+        };
+        let a_selector = match Selector::parse("a") {
+            Ok(a_selector) => a_selector,
+            Err(_) => {
+                return Err(String::from("Build a selector failed."));
+            }
+        };
+        let problem_re = match Regex::new(r"/problem/(\w+)") {
+            Ok(re) => re,
+            Err(_) => return Err(String::from("Create regex failed.")),
+        };
+        let mut hacks = Vec::new();
+        for tr in document.select(&tr_selector) {
+            let tds = tr.select(&td_selector).collect::<Vec<_>>();
+            if tds.len() < 6 {
+                return Err(String::from("Invalid hack row."));
+            }
+            let text = |td: &ElementRef| td.text().collect::<String>().trim().to_string();
+            let problem_identifier = tds[4]
+                .select(&a_selector)
+                .filter_map(|x| x.value().attr("href"))
+                .filter_map(|x| problem_re.captures(x))
+                .map(|caps| caps[1].to_string())
+                .next()
+                .unwrap_or_default();
+            let verdict_info = text(&tds[5]);
+            let waiting = verdict_info.is_empty()
+                || ["waiting", "testing", "running", "queue"]
+                    .iter()
+                    .any(|x| verdict_info.to_lowercase().contains(x));
+            hacks.push(HackInfo {
+                hack_id: tr
+                    .value()
+                    .attr("challengeid")
+                    .unwrap_or_default()
+                    .to_string(),
+                hacker: text(&tds[2]),
+                defender: text(&tds[3]),
+                problem_identifier,
+                verdict: match waiting {
+                    true => Verdict::Waiting,
+                    false => Verdict::Resulted,
+                },
+                verdict_info,
+            });
+        }
+        return Ok(hacks);
+    }
+    /// Parse submissions table, such as `/contest/{id}/my` and `/submissions/{handle}`.
+    pub fn parse_submission_list(resp: &str) -> Result<Vec<PostSubmissionInfo>, String> {
+        let document = Html::parse_document(resp);
+        let tr_selector = match Selector::parse("tr[data-submission-id]") {
+            Ok(tr_selector) => tr_selector,
+            Err(_) => {
+                return Err(String::from("Build tr selector failed."));
+            }
+        };
+        let problem_re = match Regex::new(r"/(?:contest|gym)/(\d+)/problem/(\w+)") {
+            Ok(re) => re,
+            Err(_) => return Err(String::from("Create regex failed.")),
+        };
+        let mut submissions = Vec::new();
+        for tr in document.select(&tr_selector) {
+            let tds = tr
+                .children()
+                .filter_map(ElementRef::wrap)
+                .filter(|x| x.value().name() == "td")
+                .collect::<Vec<_>>();
+            if tds.len() < 8 {
+                return Err(format!("Td count is less than 8, but {}", tds.len()));
+            }
+            let text = |index: usize| {
+                tds[index]
+                    .text()
+                    .collect::<String>()
+                    .replace('\u{a0}', " ")
+                    .trim()
+                    .to_string()
+            };
+            let mut submission_info = PostSubmissionInfo::new();
+            submission_info.submission_id = tr
+                .value()
+                .attr("data-submission-id")
+                .unwrap_or_default()
+                .to_string();
+            submission_info.submit_time = text(1);
+            let problem_href = tds[3]
+                .children()
+                .filter_map(ElementRef::wrap)
+                .find_map(|x| x.value().attr("href"))
+                .unwrap_or_default();
+            if let Some(caps) = problem_re.captures(problem_href) {
+                submission_info.contest_identifier = caps[1].to_string();
+                submission_info.problem_identifier = caps[2].to_string();
+            }
+            submission_info.language = text(4);
+            submission_info.verdict_info = text(5);
+            submission_info.failed_test =
+                Utility::get_failed_test(&submission_info.verdict_info).map(|x| x.to_string());
+            submission_info.verdict = match tds[5].value().attr("waiting") {
+                Some("true") => Verdict::Waiting,
+                _ => Verdict::Resulted,
+            };
+            submission_info.execute_time = text(6);
+            submission_info.execute_memory = text(7);
+            submissions.push(submission_info);
+        }
+        return Ok(submissions);
+    }
+    /// Parse submissions from `contest.status` API, keep the ones with verdict `OK`.
+    pub fn parse_accepted_submission_list(resp: &str) -> Result<Vec<PostSubmissionInfo>, String> {
+        let value = match serde_json::from_str::<serde_json::Value>(resp) {
+            Ok(value) => value,
+            Err(info) => {
+                return Err(format!("Parse submissions failed, {}", info));
+            }
+        };
+        if value["status"] != "OK" {
+            return Err(format!(
+                "Get submissions failed, {}",
+                value["comment"].as_str().unwrap_or("unknown error")
+            ));
+        }
+        let result = match value["result"].as_array() {
+            Some(result) => result,
+            None => {
+                return Err(String::from("Submissions not found in response."));
+            }
+        };
+        let mut submissions = Vec::new();
+        for submission in result {
+            if submission["verdict"] != "OK" {
+                continue;
+            }
+            let mut submission_info = PostSubmissionInfo::new();
+            submission_info.submission_id = submission["id"].to_string();
+            submission_info.contest_identifier = submission["contestId"].to_string();
+            submission_info.problem_identifier = submission["problem"]["index"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            submission_info.verdict = Verdict::Resulted;
+            submission_info.verdict_info = String::from("Accepted");
+            submission_info.language = submission["programmingLanguage"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            let time = submission["timeConsumedMillis"]
+                .as_u64()
+                .unwrap_or_default();
+            let memory = submission["memoryConsumedBytes"]
+                .as_u64()
+                .unwrap_or_default();
+            submission_info.execute_time = format!("{} ms", time);
+            submission_info.execute_memory = format!("{} KB", memory / 1024);
+            let submit_time = submission["creationTimeSeconds"]
+                .as_i64()
+                .unwrap_or_default();
+            if let LocalResult::Single(submit_time) = Utc.timestamp_opt(submit_time, 0) {
+                submission_info.submit_time = submit_time
+                    .with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string();
+            }
+            submissions.push(submission_info);
+        }
+        return Ok(submissions);
+    }
     pub fn parse_submission_page(
         submission_id: &str,
         contest_identifier: &str,
@@ -197,10 +695,55 @@ impl HtmlParser {
         } else {
             post_submission_info.verdict = Verdict::Resulted;
         }
+        post_submission_info.failed_test =
+            Utility::get_failed_test(&post_submission_info.verdict_info).map(|x| x.to_string());
         post_submission_info.execute_time = vec[5].text().collect::<String>().trim().to_string();
         post_submission_info.execute_memory = vec[6].text().collect::<String>().trim().to_string();
+        post_submission_info.language = vec[3].text().collect::<String>().trim().to_string();
+        post_submission_info.submit_time = vec[7].text().collect::<String>().trim().to_string();
         return Ok(post_submission_info);
     }
+    pub fn parse_submission_source(resp: &str) -> Result<SubmissionSource, String> {
+        let document = Html::parse_document(&resp);
+        let pre_selector = match Selector::parse("pre#program-source-text") {
+            Ok(pre_selector) => pre_selector,
+            Err(_) => {
+                return Err(String::from("Build pre selector failed."));
+            }
+        };
+        let a_selector = match Selector::parse(r#"table a[href*="/problem/"]"#) {
+            Ok(a_selector) => a_selector,
+            Err(_) => {
+                return Err(String::from("Build a selector failed."));
+            }
+        };
+        let code = match document.select(&pre_selector).next() {
+            Some(pre) => pre.text().collect::<String>(),
+            None => {
+                return Err(String::from("Source code not found, it may be hidden."));
+            }
+        };
+        let problem_href = match document
+            .select(&a_selector)
+            .next()
+            .and_then(|x| x.value().attr("href"))
+        {
+            Some(problem_href) => problem_href,
+            None => {
+                return Err(String::from("Select problem failed."));
+            }
+        };
+        let problem_identifier = match problem_href.rsplit('/').next() {
+            Some(problem_identifier) => problem_identifier.to_string(),
+            None => {
+                return Err(String::from("Parse problem identifier failed."));
+            }
+        };
+        return Ok(SubmissionSource {
+            problem_identifier,
+            code,
+        });
+    }
     pub fn parse_test_cases(resp: &str) -> Result<Vec<TestCase>, String> {
         let document = Html::parse_document(resp);
         let mut res = Vec::new();
@@ -273,6 +816,91 @@ impl HtmlParser {
         }
         return Ok(res);
     }
+    pub fn parse_problem_metadata(resp: &str) -> Result<ProblemMetadata, String> {
+        let document = Html::parse_document(resp);
+        let mut metadata = ProblemMetadata::default();
+        let time_limit_selector = match Selector::parse("div.problem-statement div.time-limit") {
+            Ok(time_limit_selector) => time_limit_selector,
+            Err(_) => {
+                return Err(String::from("Build time limit selector failed."));
+            }
+        };
+        let memory_limit_selector = match Selector::parse("div.problem-statement div.memory-limit")
+        {
+            Ok(memory_limit_selector) => memory_limit_selector,
+            Err(_) => {
+                return Err(String::from("Build memory limit selector failed."));
+            }
+        };
+        if let Some(time_limit) = document.select(&time_limit_selector).next() {
+            let text = time_limit.text().collect::<String>();
+            metadata.time_limit = match Utility::get_time_limit(&text) {
+                Ok(time_limit) => Some(time_limit),
+                Err(info) => {
+                    return Err(info);
+                }
+            };
+        }
+        if let Some(memory_limit) = document.select(&memory_limit_selector).next() {
+            let text = memory_limit.text().collect::<String>();
+            metadata.memory_limit = match Utility::get_memory_limit(&text) {
+                Ok(memory_limit) => Some(memory_limit),
+                Err(info) => {
+                    return Err(info);
+                }
+            };
+        }
+        if let Ok(statement) = Self::parse_statement(resp) {
+            metadata.interactive = ProblemType::is_interactive(&statement);
+            metadata.special_judge = ProblemType::is_special_judge(&statement);
+            metadata.error_tolerance = ProblemType::get_error_tolerance(&statement);
+        }
+        return Ok(metadata);
+    }
+    pub fn parse_statement(resp: &str) -> Result<String, String> {
+        let document = Html::parse_document(resp);
+        let statement_selector = match Selector::parse("div.problem-statement") {
+            Ok(statement_selector) => statement_selector,
+            Err(_) => {
+                return Err(String::from("Build statement selector failed."));
+            }
+        };
+        let statement = match document.select(&statement_selector).next() {
+            Some(statement) => statement,
+            None => {
+                return Err(String::from("Select problem statement failed."));
+            }
+        };
+        let property_selector = match Selector::parse("div.property-title") {
+            Ok(property_selector) => property_selector,
+            Err(_) => {
+                return Err(String::from("Build property selector failed."));
+            }
+        };
+        let mut markdown = String::new();
+        for child in statement.children().filter_map(ElementRef::wrap) {
+            if !child.value().classes().any(|class| class == "header") {
+                markdown.push_str(&Markdown::from_html(child));
+                markdown.push('\n');
+                continue;
+            }
+            for item in child.children().filter_map(ElementRef::wrap) {
+                let text = item.text().collect::<String>();
+                if item.value().classes().any(|class| class == "title") {
+                    markdown.push_str(&format!("# {}\n\n", text.trim()));
+                    continue;
+                }
+                let property = match item.select(&property_selector).next() {
+                    Some(property) => property.text().collect::<String>(),
+                    None => continue,
+                };
+                let value = text.strip_prefix(&property).unwrap_or(&text);
+                markdown.push_str(&format!("- {}: {}\n", property.trim(), value.trim()));
+            }
+            markdown.push('\n');
+        }
+        return Ok(markdown);
+    }
 }
 
 #[test]
@@ -280,6 +908,61 @@ fn test_parse_contest() {
     let content = std::fs::read_to_string("assets/codeforces/contest.html").unwrap();
     let contest = HtmlParser::parse_contest("1868", &content).unwrap();
     assert_eq!(contest.identifier, "1868");
+    assert_eq!(contest.registration, "Registration closed");
+}
+#[test]
+fn test_parse_contest_list() {
+    let content = std::fs::read_to_string("assets/codeforces/contest.html").unwrap();
+    let contests = HtmlParser::parse_contest_list(&content).unwrap();
+    assert_eq!(contests.len(), 1);
+    assert_eq!(contests[0].title, "Codeforces Round 896 (Div. 1)");
+    assert_eq!(contests[0].status, ContestStatus::Ended);
+}
+#[test]
+fn test_parse_standings() {
+    let content = std::fs::read_to_string("assets/codeforces/standings.json").unwrap();
+    let standings = HtmlParser::parse_standings(&content, &[]).unwrap();
+    assert_eq!(standings.problems, vec!["A", "B", "C"]);
+    assert_eq!(standings.rule, ScoringRule::Icpc);
+    assert_eq!(standings.problem_points, vec![1.0, 1.0, 1.0]);
+    assert_eq!(standings.rows.len(), 2);
+    let standings = HtmlParser::parse_standings(&content, &[String::from("XuDian")]).unwrap();
+    assert_eq!(standings.rows.len(), 1);
+    assert_eq!(standings.rows[0].rank, 1024);
+    assert_eq!(standings.rows[0].score, "2");
+    assert_eq!(standings.rows[0].problem_results, vec!["+", "+2", "-1"]);
+}
+#[test]
+fn test_parse_accepted_submission_list() {
+    let content = std::fs::read_to_string("assets/codeforces/contest_status.json").unwrap();
+    let submissions = HtmlParser::parse_accepted_submission_list(&content).unwrap();
+    assert_eq!(submissions.len(), 2);
+    assert_eq!(submissions[0].submission_id, "224181516");
+    assert_eq!(submissions[0].contest_identifier, "1868");
+    assert_eq!(submissions[0].problem_identifier, "B1");
+    assert_eq!(submissions[0].execute_time, "46 ms");
+    assert_eq!(submissions[0].execute_memory, "0 KB");
+    assert_eq!(submissions[1].problem_identifier, "A");
+    let content = r#"{"status":"FAILED","comment":"handle: User with handle ace_test not found"}"#;
+    assert!(HtmlParser::parse_accepted_submission_list(content).is_err());
+}
+
+#[test]
+fn test_parse_custom_test_result() {
+    let content = std::fs::read_to_string("assets/codeforces/custom_test_submit.json").unwrap();
+    assert_eq!(
+        HtmlParser::parse_custom_test_id(&content).unwrap(),
+        "236271042"
+    );
+    let result = HtmlParser::parse_custom_test_result(&content).unwrap();
+    assert_eq!(result.verdict, Verdict::Waiting);
+    let content = std::fs::read_to_string("assets/codeforces/custom_test_verdict.json").unwrap();
+    let result = HtmlParser::parse_custom_test_result(&content).unwrap();
+    assert_eq!(result.verdict, Verdict::Resulted);
+    assert_eq!(result.stdout, "3\n");
+    assert_eq!(result.exit_code, Some(0));
+    assert_eq!(result.execute_time, "15 ms");
+    assert_eq!(result.execute_memory, "8 KB");
 }
 #[test]
 fn test_parse_problem_list() {
@@ -294,11 +977,36 @@ fn test_parse_recent_submission_id() {
     assert_eq!(submission_id, "219682893");
 }
 #[test]
+fn test_parse_submission_list() {
+    let content = std::fs::read_to_string("assets/codeforces/recent_submission.html").unwrap();
+    let submissions = HtmlParser::parse_submission_list(&content).unwrap();
+    assert_eq!(submissions.len(), 4);
+    assert_eq!(submissions[0].submission_id, "219682893");
+    assert_eq!(submissions[0].contest_identifier, "1860");
+    assert_eq!(submissions[0].problem_identifier, "C");
+    assert_eq!(submissions[0].verdict, Verdict::Resulted);
+    assert_eq!(submissions[0].verdict_info, "Accepted");
+    assert_eq!(submissions[0].execute_time, "186 ms");
+    assert_eq!(submissions[0].execute_memory, "15400 KB");
+    assert_eq!(submissions[0].language, "GNU C++20 (64)");
+    assert_eq!(submissions[0].submit_time, "Aug/20/2023 16:44");
+    assert_eq!(submissions[3].verdict_info, "Wrong answer on test 2");
+    assert_eq!(submissions[3].failed_test, Some(String::from("2")));
+}
+#[test]
 fn test_parse_submission_page() {
     let content = std::fs::read_to_string("assets/codeforces/submission_page.html").unwrap();
     let submission_info =
         HtmlParser::parse_submission_page("219682893", "1860", "C", &content).unwrap();
     assert_eq!(submission_info.submission_id, "219682893");
+    assert_eq!(submission_info.language, "GNU C++20 (64)");
+}
+#[test]
+fn test_parse_submission_source() {
+    let content = std::fs::read_to_string("assets/codeforces/submission_page.html").unwrap();
+    let source = HtmlParser::parse_submission_source(&content).unwrap();
+    assert_eq!(source.problem_identifier, "C");
+    assert!(source.code.starts_with("#include <bits/stdc++.h>"));
 }
 #[test]
 fn test_parse_test_cases() {
@@ -306,3 +1014,76 @@ fn test_parse_test_cases() {
     let test_cases = HtmlParser::parse_test_cases(&content).unwrap();
     assert_eq!(test_cases.len(), 1);
 }
+#[test]
+fn test_parse_statement() {
+    let content = std::fs::read_to_string("assets/codeforces/test_cases.html").unwrap();
+    let statement = HtmlParser::parse_statement(&content).unwrap();
+    assert!(statement.starts_with("# C. Game on Permutation"));
+    assert!(statement.contains("- time limit per test: 2 seconds"));
+    assert!(statement.contains("$$$p$$$"));
+    assert!(statement.contains("### Note"));
+}
+#[test]
+fn test_parse_problem_metadata() {
+    let content = std::fs::read_to_string("assets/codeforces/test_cases.html").unwrap();
+    let metadata = HtmlParser::parse_problem_metadata(&content).unwrap();
+    assert_eq!(metadata.time_limit, Some(2000));
+    assert_eq!(metadata.memory_limit, Some(256));
+    assert_eq!(metadata.interactive, false);
+    assert_eq!(metadata.special_judge, false);
+}
+
+#[test]
+fn test_parse_language_options() {
+    let content = std::fs::read_to_string("assets/codeforces/submit_languages.html").unwrap();
+    let options = HtmlParser::parse_language_options(&content).unwrap();
+    assert_eq!(options.len(), 15);
+    assert_eq!(
+        options[4],
+        (
+            String::from("89"),
+            String::from("GNU G++20 13.2 (64 bit, winlibs)")
+        )
+    );
+}
+
+#[test]
+fn test_parse_hack_list() {
+    let content = std::fs::read_to_string("assets/codeforces/hacks.html").unwrap();
+    let hacks = HtmlParser::parse_hack_list(&content).unwrap();
+    assert_eq!(hacks.len(), 3);
+    assert_eq!(hacks[0].hack_id, "936125");
+    assert_eq!(hacks[0].hacker, "dianhsu");
+    assert_eq!(hacks[0].defender, "someone");
+    assert_eq!(hacks[0].problem_identifier, "C");
+    assert_eq!(hacks[0].verdict, Verdict::Waiting);
+    assert_eq!(hacks[2].verdict, Verdict::Resulted);
+    assert_eq!(hacks[2].verdict_info, "Unsuccessful hacking attempt");
+}
+
+#[test]
+fn test_parse_form_errors() {
+    let content = std::fs::read_to_string("assets/codeforces/hack_error.html").unwrap();
+    assert_eq!(
+        HtmlParser::parse_form_errors(&content),
+        Err(String::from(
+            "Validator rejected the test: integer 0 violates the range [1, 10^7]"
+        ))
+    );
+    let content = std::fs::read_to_string("assets/codeforces/problem_list.html").unwrap();
+    assert_eq!(HtmlParser::parse_form_errors(&content), Ok(()));
+}
+
+#[test]
+fn test_parse_problem_difficulties() {
+    let content = std::fs::read_to_string("assets/codeforces/problemset_problems.json").unwrap();
+    let difficulties = HtmlParser::parse_problem_difficulties(&content).unwrap();
+    assert_eq!(difficulties.len(), 6);
+    let difficulty = &difficulties["1860_c"];
+    assert_eq!(difficulty.difficulty, Some(1400));
+    assert_eq!(
+        difficulty.tags,
+        vec!["data structures", "dp", "games", "greedy"]
+    );
+    assert_eq!(difficulties["1860_f"].difficulty, None);
+}
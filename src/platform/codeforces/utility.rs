@@ -144,6 +144,15 @@ impl Utility{
         random_str::get_string(18, true, false, true, false)
     }
 
+    /// Handle in the script of pages, which is only set after login.
+    pub fn get_login_handle(body: &str) -> Option<String> {
+        let re = match Regex::new(r#"handle = "([\s\S]+?)""#) {
+            Ok(re) => re,
+            Err(_) => return None,
+        };
+        return re.captures(body).map(|caps| caps[1].to_string());
+    }
+
     pub fn get_csrf(body: &str) -> Result<String, String> {
         let re = match Regex::new(r#"csrf='(.+?)'"#) {
             Ok(re) => re,
@@ -159,4 +168,73 @@ impl Utility{
         };
         return Ok(csrf);
     }
-}
\ No newline at end of file
+
+    /// Parse time limit like `2 seconds` into milliseconds.
+    pub fn get_time_limit(text: &str) -> Result<u64, String> {
+        let re = match Regex::new(r#"([\d.]+)\s*seconds?"#) {
+            Ok(re) => re,
+            Err(_) => {
+                return Err(String::from("Create regex failed."));
+            }
+        };
+        let seconds = match re.captures(text) {
+            Some(caps) => match caps[1].parse::<f64>() {
+                Ok(seconds) => seconds,
+                Err(_) => {
+                    return Err(String::from("Parse time limit failed."));
+                }
+            },
+            None => {
+                return Err(String::from("Parse time limit failed."));
+            }
+        };
+        return Ok((seconds * 1000.0).round() as u64);
+    }
+    /// Parse memory limit like `256 megabytes` into megabytes.
+    pub fn get_memory_limit(text: &str) -> Result<u64, String> {
+        let re = match Regex::new(r#"(\d+)\s*megabytes?"#) {
+            Ok(re) => re,
+            Err(_) => {
+                return Err(String::from("Create regex failed."));
+            }
+        };
+        return match re.captures(text) {
+            Some(caps) => match caps[1].parse::<u64>() {
+                Ok(megabytes) => Ok(megabytes),
+                Err(_) => Err(String::from("Parse memory limit failed.")),
+            },
+            None => Err(String::from("Parse memory limit failed.")),
+        };
+    }
+    /// Get failed test from verdict like `Wrong answer on test 2` or `Time limit exceeded on pretest 5`.
+    pub fn get_failed_test(verdict_info: &str) -> Option<u32> {
+        let re = match Regex::new(r#"on (pre)?test (\d+)"#) {
+            Ok(re) => re,
+            Err(_) => return None,
+        };
+        return match re.captures(verdict_info) {
+            Some(caps) => caps[2].parse::<u32>().ok(),
+            None => None,
+        };
+    }
+}
+
+#[test]
+fn test_get_failed_test() {
+    assert_eq!(Utility::get_failed_test("Wrong answer on test 2"), Some(2));
+    assert_eq!(
+        Utility::get_failed_test("Time limit exceeded on pretest 15"),
+        Some(15)
+    );
+    assert_eq!(Utility::get_failed_test("Accepted"), None);
+}
+
+#[test]
+fn test_get_login_handle() {
+    let content = std::fs::read_to_string("assets/codeforces/contest.html").unwrap();
+    assert_eq!(
+        Utility::get_login_handle(&content),
+        Some(String::from("xudian"))
+    );
+    assert_eq!(Utility::get_login_handle("<html></html>"), None);
+}
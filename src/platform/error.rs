@@ -0,0 +1,53 @@
+use std::fmt::Display;
+
+use crate::utility::http_error::HttpError;
+
+/// Errors of online judges, errors of requests keep their type so that
+/// the hint of a blocked request is shown once when the command fails.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OnlineJudgeError {
+    Http(HttpError),
+    /// Errors of parsing pages, login or saving data.
+    Other(String),
+}
+
+impl OnlineJudgeError {
+    /// What to do about the error, only blocked requests have hints.
+    pub fn hint(&self) -> Option<&'static str> {
+        return match self {
+            Self::Http(err) => err.hint(),
+            Self::Other(_) => None,
+        };
+    }
+}
+
+impl From<HttpError> for OnlineJudgeError {
+    fn from(err: HttpError) -> Self {
+        return Self::Http(err);
+    }
+}
+
+impl From<String> for OnlineJudgeError {
+    fn from(info: String) -> Self {
+        return Self::Other(info);
+    }
+}
+
+impl Display for OnlineJudgeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Http(err) => write!(f, "{}", err),
+            Self::Other(info) => write!(f, "{}", info),
+        }
+    }
+}
+
+#[test]
+fn test_hint() {
+    let err = OnlineJudgeError::from(HttpError::Challenge(String::from("codeforces.com")));
+    assert!(err.hint().unwrap().contains("ace account import-cookies"));
+    assert!(!err.to_string().contains("ace account import-cookies"));
+    let err = OnlineJudgeError::from(String::from("Login failed."));
+    assert_eq!(err.hint(), None);
+    assert_eq!(err.to_string(), "Login failed.");
+}
@@ -1,19 +1,36 @@
 mod atcoder;
 mod codeforces;
+pub mod error;
 mod traits;
 
+use std::collections::HashMap;
+
+use chrono::{DateTime, Local};
 use colored::Colorize;
+use futures_util::{stream, StreamExt};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 
 use crate::{
     database::CONFIG_DB,
     model::{
-        AccountInfo, Contest, ContestStatus, Platform, PlatformLanguage, PostSubmissionInfo,
-        TestCase,
+        AccountInfo, Contest, ContestStatus, CustomTestResult, HackInfo, Platform,
+        PlatformLanguage, PostSubmissionInfo, ProblemDetail, ProblemDifficulty, ProblemMetadata,
+        Standings, SubmissionSource,
     },
     utility::http_client::HttpClient,
 };
 
-use self::{atcoder::AtCoder, codeforces::Codeforces, traits::OnlineJudgeBehavior};
+use self::{
+    atcoder::AtCoder, codeforces::Codeforces, error::OnlineJudgeError, traits::OnlineJudgeBehavior,
+};
+
+/// Problem pages fetched at the same time when parsing a contest.
+const MAX_CONCURRENT_PROBLEM_FETCHES: usize = 4;
+/// Warn if the session of an account without password expires within these hours.
+const SESSION_EXPIRE_WARNING_HOURS: i64 = 72;
+/// Pages of submissions read at most when looking for accepted submissions.
+const MAX_SUBMISSION_LIST_PAGES: usize = 20;
+
 pub struct OnlineJudge {
     pub platform: Platform,
     pub username: String,
@@ -27,21 +44,52 @@ impl Drop for OnlineJudge {
 }
 
 impl OnlineJudge {
-    pub fn new(account_info: AccountInfo, platform: Platform) -> Self {
-        let endpoint = match platform {
-            Platform::Codeforces => Codeforces::build_endpoint_url(),
-            Platform::AtCoder => AtCoder::build_endpoint_url(),
+    pub fn new(account_info: AccountInfo, platform: Platform) -> Result<Self, String> {
+        let endpoint = Self::get_endpoint_url(platform);
+        let proxy = Self::get_proxy(platform);
+        let http_client = match HttpClient::new(&account_info.cookies, &endpoint, proxy.as_deref())
+        {
+            Ok(http_client) => http_client,
+            Err(info) => return Err(info),
         };
-        let http_client = HttpClient::new(&account_info.cookies, &endpoint);
-        Self {
+        let oj = Self {
             platform,
             username: account_info.username,
             password: account_info.password,
             http_client,
+        };
+        oj.check_session_expires();
+        return Ok(oj);
+    }
+    /// Accounts with password login again when the session expires,
+    /// others should import cookies again before that.
+    fn check_session_expires(&self) {
+        if self.username.is_empty() || !self.password.is_empty() {
+            return;
+        }
+        let name = match self.platform {
+            Platform::Codeforces => Codeforces::get_session_cookie_name(),
+            Platform::AtCoder => AtCoder::get_session_cookie_name(),
+        };
+        let expires = match self.http_client.get_cookie_expires(&name) {
+            Some(expires) => expires,
+            None => return,
+        };
+        let hours = (expires - Local::now().timestamp()) / 3600;
+        if hours < SESSION_EXPIRE_WARNING_HOURS {
+            println!(
+                "{}",
+                format!(
+                    "Session of {} on {} expires in {} hours, run `ace account import-cookies` again.",
+                    self.username, self.platform, hours
+                )
+                .yellow()
+            );
         }
     }
     fn save_cookies(&mut self) -> Result<(), String> {
-        if !self.username.is_empty() {
+        // Cookies replayed from or recorded into cassettes are not of the real session.
+        if !self.username.is_empty() && !self.http_client.has_cassette() {
             return CONFIG_DB.save_cookies(
                 self.platform,
                 &self.username,
@@ -58,14 +106,14 @@ impl OnlineJudge {
         full_problem_identifier: &str,
         code: &str,
         lang_id: &str,
-    ) -> Result<String, String> {
+    ) -> Result<String, OnlineJudgeError> {
          if let Err(info) = self.login().await{
             return Err(info);
         }
         let vec = full_problem_identifier.split("_").collect::<Vec<_>>();
         log::info!("vec: {:?}", vec);
         if vec.len() != 2 {
-            return Err(String::from("Invalid problem identifier."));
+            return Err(String::from("Invalid problem identifier.").into());
         }
         let contest_identifier = vec[0];
         let problem_identifier = vec[1];
@@ -76,7 +124,7 @@ impl OnlineJudge {
         };
         let resp = match self.http_client.get(&submit_page_url).await {
             Ok(resp) => resp,
-            Err(info) => return Err(info),
+            Err(info) => return Err(info.into()),
         };
 
         let csrf_token = match match self.platform {
@@ -85,7 +133,7 @@ impl OnlineJudge {
                 } {
             Ok(token) => {token},
             Err(info) => {
-                return Err(info);
+                return Err(info.into());
             },
         };
         
@@ -111,36 +159,166 @@ impl OnlineJudge {
         };
         let resp = match self.http_client.post_form(&submit_url, &data).await {
             Ok(resp) => resp,
-            Err(info) => return Err(info),
+            Err(info) => return Err(info.into()),
         };
 
         return match self.platform {
             Platform::Codeforces => Codeforces::parse_recent_submission_id(&resp),
             Platform::AtCoder => AtCoder::parse_recent_submission_id(&resp),
+        }
+        .map_err(OnlineJudgeError::from);
+    }
+
+    /// Run code with custom input on the judge.
+    /// # Returns
+    /// * `Ok((String, String))` - Id of the custom test and csrf token to retrive its result.
+    pub async fn custom_test(
+        &mut self,
+        contest_identifier: &str,
+        code: &str,
+        lang_id: &str,
+        input: &str,
+    ) -> Result<(String, String), OnlineJudgeError> {
+        if let Err(info) = self.login().await {
+            return Err(info);
+        }
+        let custom_test_page_url = match self.platform {
+            Platform::Codeforces => Codeforces::build_custom_test_page_url(contest_identifier),
+            Platform::AtCoder => AtCoder::build_custom_test_page_url(contest_identifier),
         };
+        let resp = match self.http_client.get(&custom_test_page_url).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        let csrf_token = match match self.platform {
+            Platform::Codeforces => Codeforces::get_csrf_token(&resp),
+            Platform::AtCoder => AtCoder::get_csrf_token(&resp),
+        } {
+            Ok(csrf_token) => csrf_token,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let custom_test_url = match self.platform {
+            Platform::Codeforces => Codeforces::build_custom_test_url(contest_identifier),
+            Platform::AtCoder => AtCoder::build_custom_test_url(contest_identifier),
+        };
+        let data = match self.platform {
+            Platform::Codeforces => {
+                Codeforces::build_custom_test_request_data(code, lang_id, input, &csrf_token)
+            }
+            Platform::AtCoder => {
+                AtCoder::build_custom_test_request_data(code, lang_id, input, &csrf_token)
+            }
+        };
+        let resp = match self.http_client.post_form(&custom_test_url, &data).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        let custom_test_id = match match self.platform {
+            Platform::Codeforces => Codeforces::parse_custom_test_id(&resp),
+            Platform::AtCoder => AtCoder::parse_custom_test_id(&resp),
+        } {
+            Ok(custom_test_id) => custom_test_id,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        return Ok((custom_test_id, csrf_token));
     }
 
-    pub async fn is_login(&mut self) -> bool {
+    pub async fn retrive_custom_test_result(
+        &mut self,
+        contest_identifier: &str,
+        custom_test_id: &str,
+        csrf_token: &str,
+    ) -> Result<CustomTestResult, OnlineJudgeError> {
+        let result_url = match self.platform {
+            Platform::Codeforces => Codeforces::build_custom_test_result_url(contest_identifier),
+            Platform::AtCoder => AtCoder::build_custom_test_result_url(contest_identifier),
+        };
+        let data = match self.platform {
+            Platform::Codeforces => {
+                Codeforces::build_custom_test_result_request_data(custom_test_id, csrf_token)
+            }
+            Platform::AtCoder => {
+                AtCoder::build_custom_test_result_request_data(custom_test_id, csrf_token)
+            }
+        };
+        let resp = match data {
+            Some(data) => self.http_client.post_form(&result_url, &data).await,
+            None => self.http_client.get(&result_url).await,
+        };
+        let resp = match resp {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        return match self.platform {
+            Platform::Codeforces => Codeforces::parse_custom_test_result(&resp),
+            Platform::AtCoder => AtCoder::parse_custom_test_result(&resp),
+        }
+        .map_err(OnlineJudgeError::from);
+    }
+
+    /// Blocked requests are returned as errors instead of logging in again.
+    pub async fn is_login(&mut self) -> Result<bool, OnlineJudgeError> {
         let check_login_url = match self.platform {
             Platform::Codeforces => Codeforces::build_check_login_url(),
             Platform::AtCoder => AtCoder::build_check_login_url(),
         };
         let main_page = match self.http_client.get(&check_login_url).await {
             Ok(resp) => resp,
-            Err(info) => {
-                log::error!("Failed to get main page: {}", info);
-                return false;
-            }
+            Err(info) => return Err(info.into()),
         };
-        match self.platform {
+        return Ok(match self.platform {
             Platform::Codeforces => Codeforces::check_login(&main_page),
             Platform::AtCoder => AtCoder::check_login(&main_page),
-        }
+        });
+    }
+
+    /// Check if the cookies are of a logged in session, without touching saved accounts.
+    /// # Returns
+    /// * `Ok(String)` - Cookies of the session after checking.
+    pub async fn check_cookies(
+        platform: Platform,
+        cookies: &str,
+    ) -> Result<(String, String), OnlineJudgeError> {
+        let endpoint = Self::get_endpoint_url(platform);
+        let check_login_url = match platform {
+            Platform::Codeforces => Codeforces::build_check_login_url(),
+            Platform::AtCoder => AtCoder::build_check_login_url(),
+        };
+        let proxy = Self::get_proxy(platform);
+        let mut http_client = match HttpClient::new(cookies, &endpoint, proxy.as_deref()) {
+            Ok(http_client) => http_client,
+            Err(info) => return Err(info.into()),
+        };
+        let resp = match http_client.get(&check_login_url).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        let handle = match platform {
+            Platform::Codeforces => Codeforces::get_login_handle(&resp),
+            Platform::AtCoder => AtCoder::get_login_handle(&resp),
+        };
+        return match handle {
+            Some(handle) => Ok((handle, http_client.save_cookies())),
+            None => Err(String::from("Cookies are not of a logged in session.").into()),
+        };
     }
 
-    pub async fn login(&mut self) -> Result<String, String> {
-        if self.is_login().await {
-            return Ok(String::from("Already login."));
+    pub async fn login(&mut self) -> Result<String, OnlineJudgeError> {
+        match self.is_login().await {
+            Ok(true) => return Ok(String::from("Already login.")),
+            Ok(false) => {}
+            Err(info) => return Err(info),
+        }
+        // Accounts with imported cookies have no password to login again.
+        if self.password.is_empty() {
+            return Err(String::from(
+                "Session expired, please run `ace account import-cookies` again.",
+            )
+            .into());
         }
         let login_page_url = match self.platform {
             Platform::Codeforces => Codeforces::build_login_page_url(),
@@ -148,7 +326,7 @@ impl OnlineJudge {
         };
         let resp = match self.http_client.get(&login_page_url).await {
             Ok(resp) => resp,
-            Err(info) => return Err(info),
+            Err(info) => return Err(info.into()),
         };
         let csrf_token = match match self.platform {
             Platform::Codeforces => Codeforces::get_csrf_token(&resp),
@@ -156,7 +334,7 @@ impl OnlineJudge {
         } {
             Ok(csrf_token) => csrf_token,
             Err(info) => {
-                return Err(info);
+                return Err(info.into());
             }
         };
         let data = match self.platform {
@@ -174,20 +352,20 @@ impl OnlineJudge {
         };
         let _ = match self.http_client.post_form(&login_url, &data).await {
             Ok(_) => {}
-            Err(info) => return Err(info),
+            Err(info) => return Err(info.into()),
+        };
+        return match self.is_login().await {
+            Ok(true) => Ok(String::from("Login successfully.")),
+            Ok(false) => Err(String::from("Login failed.").into()),
+            Err(info) => Err(info),
         };
-        if self.is_login().await {
-            return Ok(String::from("Login successfully."));
-        } else {
-            return Err(String::from("Login failed."));
-        }
     }
 
     /// Get all problems in a contest, return a vector of problem identifier and problem url  
     pub async fn get_problems(
         &mut self,
         contest_identifier: &str,
-    ) -> Result<Vec<[String; 2]>, String> {
+    ) -> Result<Vec<[String; 2]>, OnlineJudgeError> {
         if let Err(info) = self.login().await {
             return Err(info);
         }
@@ -195,35 +373,76 @@ impl OnlineJudge {
             Platform::Codeforces => Codeforces::build_problem_list_url(contest_identifier),
             Platform::AtCoder => AtCoder::build_problem_list_url(contest_identifier),
         };
+        // Never cached, the page before problems are published is also served with status 200.
         let resp = match self.http_client.get(&problem_list_url).await {
             Ok(resp) => resp,
-            Err(info) => return Err(info),
+            Err(info) => return Err(info.into()),
         };
         return match self.platform {
             Platform::Codeforces => Codeforces::parse_problem_list(contest_identifier, &resp),
             Platform::AtCoder => AtCoder::parse_problem_list(contest_identifier, &resp),
-        };
+        }
+        .map_err(OnlineJudgeError::from);
     }
 
-    pub async fn get_test_cases(&mut self, problem_url: &str) -> Result<Vec<TestCase>, String> {
-        let resp = match self.http_client.get(&problem_url).await {
+    /// # Arguments
+    /// * `use_page_cache` - Whether the page can be read from and saved into the page cache.
+    pub async fn get_problem_detail(
+        &self,
+        problem_url: &str,
+        use_page_cache: bool,
+    ) -> Result<ProblemDetail, OnlineJudgeError> {
+        let resp = match use_page_cache {
+            true => self.http_client.get_cached(problem_url).await,
+            false => self.http_client.get(problem_url).await,
+        };
+        let resp = match resp {
             Ok(resp) => resp,
-            Err(info) => return Err(info),
+            Err(info) => return Err(info.into()),
         };
-        match self.platform {
+        let test_cases = match self.platform {
             Platform::Codeforces => Codeforces::parse_test_cases(&resp),
             Platform::AtCoder => AtCoder::parse_test_cases(&resp),
-        }
+        };
+        let test_cases = match test_cases {
+            Ok(test_cases) => test_cases,
+            Err(info) => return Err(info.into()),
+        };
+        let statement = match match self.platform {
+            Platform::Codeforces => Codeforces::parse_statement(&resp),
+            Platform::AtCoder => AtCoder::parse_statement(&resp),
+        } {
+            Ok(statement) => statement,
+            Err(info) => {
+                log::warn!("Parse statement from {} failed: {}", problem_url, info);
+                String::new()
+            }
+        };
+        let metadata = match match self.platform {
+            Platform::Codeforces => Codeforces::parse_problem_metadata(&resp),
+            Platform::AtCoder => AtCoder::parse_problem_metadata(&resp),
+        } {
+            Ok(metadata) => metadata,
+            Err(info) => {
+                log::warn!("Parse metadata from {} failed: {}", problem_url, info);
+                ProblemMetadata::default()
+            }
+        };
+        return Ok(ProblemDetail {
+            test_cases,
+            statement,
+            metadata,
+        });
     }
 
     pub async fn retrive_result(
         &mut self,
         full_problem_identifier: &str,
         submission_id: &str,
-    ) -> Result<PostSubmissionInfo, String> {
+    ) -> Result<PostSubmissionInfo, OnlineJudgeError> {
         let vec = full_problem_identifier.split("_").collect::<Vec<_>>();
         if vec.len() != 2 {
-            return Err(String::from("Invalid problem identifier."));
+            return Err(String::from("Invalid problem identifier.").into());
         }
         let contest_identifier = vec[0];
         let problem_identifier = vec[1];
@@ -235,7 +454,7 @@ impl OnlineJudge {
         };
         let resp = match self.http_client.get(&submission_url).await {
             Ok(resp) => resp,
-            Err(info) => return Err(info),
+            Err(info) => return Err(info.into()),
         };
         match self.platform {
             Platform::Codeforces => Codeforces::parse_submission_page(
@@ -251,9 +470,275 @@ impl OnlineJudge {
                 &resp,
             ),
         }
+        .map_err(OnlineJudgeError::from)
     }
 
-    pub async fn get_contest(&mut self, contest_identifier: &str) -> Result<Contest, String> {
+    /// Download source code and verdict of a submission.
+    pub async fn fetch_submission(
+        &mut self,
+        contest_identifier: &str,
+        submission_id: &str,
+    ) -> Result<(PostSubmissionInfo, SubmissionSource), OnlineJudgeError> {
+        if let Err(info) = self.login().await {
+            return Err(info);
+        }
+        let submission_url = match self.platform {
+            Platform::Codeforces => {
+                Codeforces::build_submission_url(contest_identifier, submission_id)
+            }
+            Platform::AtCoder => AtCoder::build_submission_url(contest_identifier, submission_id),
+        };
+        let resp = match self.http_client.get(&submission_url).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        let source = match match self.platform {
+            Platform::Codeforces => Codeforces::parse_submission_source(&resp),
+            Platform::AtCoder => AtCoder::parse_submission_source(&resp),
+        } {
+            Ok(source) => source,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let submission_info = match match self.platform {
+            Platform::Codeforces => Codeforces::parse_submission_page(
+                contest_identifier,
+                &source.problem_identifier,
+                submission_id,
+                &resp,
+            ),
+            Platform::AtCoder => AtCoder::parse_submission_page(
+                contest_identifier,
+                &source.problem_identifier,
+                submission_id,
+                &resp,
+            ),
+        } {
+            Ok(submission_info) => submission_info,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        return Ok((submission_info, source));
+    }
+
+    /// Get standings of a contest, only rows of `handles` if it's not empty.
+    pub async fn get_standings(
+        &mut self,
+        contest_identifier: &str,
+        handles: &[String],
+    ) -> Result<Standings, OnlineJudgeError> {
+        if let Err(info) = self.login().await {
+            return Err(info);
+        }
+        let standings_url = match self.platform {
+            Platform::Codeforces => Codeforces::build_standings_url(contest_identifier, handles),
+            Platform::AtCoder => AtCoder::build_standings_url(contest_identifier, handles),
+        };
+        let resp = match self.http_client.get(&standings_url).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        return match self.platform {
+            Platform::Codeforces => Codeforces::parse_standings(&resp, handles),
+            Platform::AtCoder => AtCoder::parse_standings(&resp, handles),
+        }
+        .map_err(OnlineJudgeError::from);
+    }
+
+    /// Get hacks of a contest, the latest hack comes first.
+    pub async fn get_hacks(
+        &mut self,
+        contest_identifier: &str,
+    ) -> Result<Vec<HackInfo>, OnlineJudgeError> {
+        if let Err(info) = self.login().await {
+            return Err(info);
+        }
+        let hack_list_url = match self.platform {
+            Platform::Codeforces => Codeforces::build_hack_list_url(contest_identifier),
+            Platform::AtCoder => AtCoder::build_hack_list_url(contest_identifier),
+        };
+        let resp = match self.http_client.get(&hack_list_url).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        return match self.platform {
+            Platform::Codeforces => Codeforces::parse_hack_list(&resp),
+            Platform::AtCoder => AtCoder::parse_hack_list(&resp),
+        }
+        .map_err(OnlineJudgeError::from);
+    }
+
+    /// Hack a submission with `input`, or with a generator whose command line is `input`.
+    /// # Arguments
+    /// * `generator` - Source code and language id of the generator.
+    pub async fn hack(
+        &mut self,
+        contest_identifier: &str,
+        submission_id: &str,
+        input: &str,
+        generator: Option<(&str, &str)>,
+    ) -> Result<(), OnlineJudgeError> {
+        let hack_url = match match self.platform {
+            Platform::Codeforces => Codeforces::build_hack_url(contest_identifier),
+            Platform::AtCoder => AtCoder::build_hack_url(contest_identifier),
+        } {
+            Some(hack_url) => hack_url,
+            None => {
+                return Err(format!("Hacks are not supported on {}.", self.platform).into());
+            }
+        };
+        if let Err(info) = self.login().await {
+            return Err(info);
+        }
+        let hack_list_url = match self.platform {
+            Platform::Codeforces => Codeforces::build_hack_list_url(contest_identifier),
+            Platform::AtCoder => AtCoder::build_hack_list_url(contest_identifier),
+        };
+        let resp = match self.http_client.get(&hack_list_url).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        let csrf_token = match match self.platform {
+            Platform::Codeforces => Codeforces::get_csrf_token(&resp),
+            Platform::AtCoder => AtCoder::get_csrf_token(&resp),
+        } {
+            Ok(csrf_token) => csrf_token,
+            Err(info) => {
+                return Err(info.into());
+            }
+        };
+        let data = match self.platform {
+            Platform::Codeforces => {
+                Codeforces::build_hack_request_data(submission_id, input, generator, &csrf_token)
+            }
+            Platform::AtCoder => {
+                AtCoder::build_hack_request_data(submission_id, input, generator, &csrf_token)
+            }
+        };
+        let resp = match self.http_client.post_form(&hack_url, &data).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        return match self.platform {
+            Platform::Codeforces => Codeforces::parse_hack_result(&resp),
+            Platform::AtCoder => AtCoder::parse_hack_result(&resp),
+        }
+        .map_err(OnlineJudgeError::from);
+    }
+
+    /// Get standings with rows of all official participants.
+    pub async fn get_full_standings(
+        &mut self,
+        contest_identifier: &str,
+    ) -> Result<Standings, OnlineJudgeError> {
+        if let Err(info) = self.login().await {
+            return Err(info);
+        }
+        let standings_url = match self.platform {
+            Platform::Codeforces => Codeforces::build_full_standings_url(contest_identifier),
+            Platform::AtCoder => AtCoder::build_full_standings_url(contest_identifier),
+        };
+        let resp = match self.http_client.get(&standings_url).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        return match self.platform {
+            Platform::Codeforces => Codeforces::parse_standings(&resp, &[]),
+            Platform::AtCoder => AtCoder::parse_standings(&resp, &[]),
+        }
+        .map_err(OnlineJudgeError::from);
+    }
+
+    /// Get submissions of current account, in a contest if `contest_identifier` is given,
+    /// otherwise in all contests.
+    pub async fn get_submissions(
+        &mut self,
+        contest_identifier: Option<&str>,
+    ) -> Result<Vec<PostSubmissionInfo>, OnlineJudgeError> {
+        if let Err(info) = self.login().await {
+            return Err(info);
+        }
+        let submission_list_url = match contest_identifier {
+            Some(contest_identifier) => match self.platform {
+                Platform::Codeforces => Codeforces::build_submission_list_url(contest_identifier),
+                Platform::AtCoder => AtCoder::build_submission_list_url(contest_identifier),
+            },
+            None => match match self.platform {
+                Platform::Codeforces => Codeforces::build_user_submission_list_url(&self.username),
+                Platform::AtCoder => AtCoder::build_user_submission_list_url(&self.username),
+            } {
+                Some(url) => url,
+                None => {
+                    return Err(
+                        format!("Contest identifier is required for {}.", self.platform).into(),
+                    );
+                }
+            },
+        };
+        let resp = match self.http_client.get(&submission_list_url).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        return match self.platform {
+            Platform::Codeforces => Codeforces::parse_submission_list(&resp),
+            Platform::AtCoder => AtCoder::parse_submission_list(&resp),
+        }
+        .map_err(OnlineJudgeError::from);
+    }
+
+    /// Get accepted submissions of current account in the contest, reading all pages.
+    pub async fn get_accepted_submissions(
+        &mut self,
+        contest_identifier: &str,
+    ) -> Result<Vec<PostSubmissionInfo>, OnlineJudgeError> {
+        if let Err(info) = self.login().await {
+            return Err(info);
+        }
+        let mut submissions: Vec<PostSubmissionInfo> = Vec::new();
+        for page in 1..=MAX_SUBMISSION_LIST_PAGES {
+            let submission_list_url = match self.platform {
+                Platform::Codeforces => Codeforces::build_accepted_submission_list_url(
+                    contest_identifier,
+                    &self.username,
+                    page,
+                ),
+                Platform::AtCoder => AtCoder::build_accepted_submission_list_url(
+                    contest_identifier,
+                    &self.username,
+                    page,
+                ),
+            };
+            let submission_list_url = match submission_list_url {
+                Some(submission_list_url) => submission_list_url,
+                None => break,
+            };
+            let resp = match self.http_client.get(&submission_list_url).await {
+                Ok(resp) => resp,
+                Err(info) => return Err(info.into()),
+            };
+            let page_submissions = match self.platform {
+                Platform::Codeforces => Codeforces::parse_accepted_submission_list(&resp),
+                Platform::AtCoder => AtCoder::parse_accepted_submission_list(&resp),
+            };
+            let page_submissions = match page_submissions {
+                Ok(page_submissions) => page_submissions,
+                Err(info) => return Err(info.into()),
+            };
+            // Pages after the last one are empty on AtCoder.
+            if page_submissions.is_empty() {
+                break;
+            }
+            submissions.extend(page_submissions);
+        }
+        return Ok(submissions);
+    }
+
+    pub async fn get_contest(
+        &mut self,
+        contest_identifier: &str,
+    ) -> Result<Contest, OnlineJudgeError> {
         if let Err(info) = self.login().await {
             return Err(info);
         }
@@ -263,17 +748,93 @@ impl OnlineJudge {
         };
         let resp = match self.http_client.get(&contest_url).await {
             Ok(resp) => resp,
-            Err(info) => return Err(info),
+            Err(info) => return Err(info.into()),
         };
         return match self.platform {
             Platform::Codeforces => Codeforces::parse_contest(contest_identifier, &resp),
             Platform::AtCoder => AtCoder::parse_contest(contest_identifier, &resp),
+        }
+        .map_err(OnlineJudgeError::from);
+    }
+    /// Get running and upcoming contests.
+    pub async fn get_contests(&mut self) -> Result<Vec<Contest>, OnlineJudgeError> {
+        // Registration state is only shown after login.
+        if !self.username.is_empty() {
+            if let Err(info) = self.login().await {
+                return Err(info);
+            }
+        }
+        let contest_list_url = match self.platform {
+            Platform::Codeforces => Codeforces::build_contest_list_url(),
+            Platform::AtCoder => AtCoder::build_contest_list_url(),
+        };
+        let resp = match self.http_client.get(&contest_list_url).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        let contests = match self.platform {
+            Platform::Codeforces => Codeforces::parse_contest_list(&resp),
+            Platform::AtCoder => AtCoder::parse_contest_list(&resp),
+        };
+        return match contests {
+            Ok(contests) => Ok(contests
+                .into_iter()
+                .filter(|x| x.status != ContestStatus::Ended)
+                .collect()),
+            Err(info) => Err(info.into()),
         };
     }
-    pub async fn get_contest_test_cases(
+
+    pub fn get_endpoint_url(platform: Platform) -> String {
+        match platform {
+            Platform::Codeforces => Codeforces::build_endpoint_url(),
+            Platform::AtCoder => AtCoder::build_endpoint_url(),
+        }
+    }
+
+    /// Proxy of the platform in config `<platform>-proxy`, or config `proxy` for all platforms.
+    fn get_proxy(platform: Platform) -> Option<String> {
+        let name = format!("{}-proxy", platform.to_string().to_lowercase());
+        for name in [name, String::from("proxy")] {
+            if let Ok(proxy) = CONFIG_DB.get_config(&name) {
+                if !proxy.trim().is_empty() {
+                    return Some(proxy.trim().to_string());
+                }
+            }
+        }
+        return None;
+    }
+
+    pub fn get_contest_url(platform: Platform, contest_identifier: &str) -> String {
+        match platform {
+            Platform::Codeforces => Codeforces::build_contest_url(contest_identifier),
+            Platform::AtCoder => AtCoder::build_contest_url(contest_identifier),
+        }
+    }
+
+    pub fn get_problem_url(
+        platform: Platform,
+        contest_identifier: &str,
+        problem_identifier: &str,
+    ) -> String {
+        match platform {
+            Platform::Codeforces => {
+                Codeforces::build_problem_url(contest_identifier, problem_identifier)
+            }
+            Platform::AtCoder => AtCoder::build_problem_url(contest_identifier, problem_identifier),
+        }
+    }
+
+    /// Fetch problems of the contest concurrently, `on_problem` is called with the problem
+    /// identifier and detail as soon as each problem arrives, in the order of arrival.
+    pub async fn get_contest_test_cases<F>(
         &mut self,
         contest_identifier: &str,
-    ) -> Result<Vec<(String, Vec<TestCase>)>, String> {
+        mut on_problem: F,
+    ) -> Result<(), OnlineJudgeError>
+    where
+        F: FnMut(&str, ProblemDetail) -> Result<(), String>,
+    {
         let contest = match self.get_contest(contest_identifier).await {
             Ok(contest) => contest,
             Err(info) => {
@@ -281,38 +842,307 @@ impl OnlineJudge {
             }
         };
         println!("Get contest {} success.", contest_identifier.bright_blue());
-        let mut contest_test_cases = Vec::new();
-        if contest.status != ContestStatus::NotStarted {
-            let problem_infos = match self.get_problems(contest_identifier).await {
-                Ok(problem_infos) => problem_infos,
+        if contest.status == ContestStatus::NotStarted {
+            return Err(format!("Contest {} not started", contest_identifier).into());
+        }
+        let problem_infos = match self.get_problems(contest_identifier).await {
+            Ok(problem_infos) => problem_infos,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        // Difficulties are only rated after the contest ends.
+        let difficulties = match contest.status {
+            ContestStatus::Ended => match self.get_problem_difficulties().await {
+                Ok(difficulties) => difficulties,
+                Err(info) => {
+                    log::warn!("Get problem difficulties failed: {}", info);
+                    HashMap::new()
+                }
+            },
+            _ => HashMap::new(),
+        };
+        let multi_progress = MultiProgress::new();
+        let style = ProgressStyle::with_template("{spinner:.blue} {msg}")
+            .unwrap_or_else(|_| ProgressStyle::default_spinner());
+        // Requests are still limited by the rate limiter of the host.
+        let use_page_cache = Self::is_page_cacheable(&contest.status);
+        let oj = &*self;
+        let mut problem_details = stream::iter(problem_infos.iter())
+            .map(|problem_info| {
+                let progress_bar = multi_progress.add(ProgressBar::new_spinner());
+                progress_bar.set_style(style.clone());
+                progress_bar.set_message(format!("Get test case for {}", problem_info[0]));
+                progress_bar.enable_steady_tick(std::time::Duration::from_millis(100));
+                async move {
+                    let problem_detail = oj
+                        .get_problem_detail(&problem_info[1], use_page_cache)
+                        .await;
+                    (problem_info, progress_bar, problem_detail)
+                }
+            })
+            .buffer_unordered(MAX_CONCURRENT_PROBLEM_FETCHES);
+        while let Some((problem_info, progress_bar, problem_detail)) = problem_details.next().await
+        {
+            let mut problem_detail = match problem_detail {
+                Ok(problem_detail) => problem_detail,
                 Err(info) => {
+                    progress_bar.abandon_with_message(format!(
+                        "Get test case for {} failed.",
+                        problem_info[0].red()
+                    ));
                     return Err(info);
                 }
             };
-            for problem_info in problem_infos {
-                let test_cases = match self.get_test_cases(&problem_info[1]).await {
-                    Ok(test_cases) => test_cases,
-                    Err(info) => {
-                        return Err(info);
-                    }
-                };
-                println!(
-                    "Get test case for {} success.",
-                    problem_info[0].bright_blue()
-                );
-                let problem_identifier = problem_info[0].clone();
-                contest_test_cases.push((problem_identifier, test_cases));
+            let difficulty_key = match oj.platform {
+                Platform::Codeforces => {
+                    Codeforces::get_problem_difficulty_key(&problem_info[0], &problem_info[1])
+                }
+                Platform::AtCoder => {
+                    AtCoder::get_problem_difficulty_key(&problem_info[0], &problem_info[1])
+                }
+            };
+            if let Some(difficulty) = difficulties.get(&difficulty_key) {
+                problem_detail.metadata.difficulty = difficulty.difficulty;
+                problem_detail.metadata.tags = difficulty.tags.clone();
+            }
+            if let Err(info) = on_problem(&problem_info[0], problem_detail) {
+                progress_bar.abandon_with_message(format!(
+                    "Save test case for {} failed.",
+                    problem_info[0].red()
+                ));
+                return Err(info.into());
+            }
+            let message = format!(
+                "Get test case for {} success.",
+                problem_info[0].bright_blue()
+            );
+            // Progress bars are not drawn if the output is not a terminal.
+            if multi_progress.is_hidden() {
+                println!("{}", message);
             }
-        } else {
-            return Err(format!("Contest {} not started", contest_identifier));
+            progress_bar.finish_with_message(message);
         }
-        return Ok(contest_test_cases);
+        return Ok(());
+    }
+
+    /// Pages of contests not ended may change, such as problems published at the start.
+    fn is_page_cacheable(status: &ContestStatus) -> bool {
+        return *status == ContestStatus::Ended;
     }
 
+    /// Languages refreshed from submit page if cached, otherwise the hard-coded ones.
     pub fn get_platform_languages(platform: Platform) -> Vec<PlatformLanguage> {
+        if let Ok(value) = CONFIG_DB.get_config(&Self::get_languages_config_name(platform)) {
+            match serde_json::from_str::<Vec<PlatformLanguage>>(&value) {
+                Ok(languages) => return languages,
+                Err(info) => log::warn!("Failed to load cached languages, {}", info),
+            }
+        }
         match platform {
             Platform::Codeforces => Codeforces::get_platform_languages(),
             Platform::AtCoder => AtCoder::get_platform_languages(),
         }
     }
+
+    /// Time of last refreshing languages, `None` if languages are never refreshed.
+    pub fn get_platform_languages_updated_time(platform: Platform) -> Option<DateTime<Local>> {
+        let name = format!("{}-updated", Self::get_languages_config_name(platform));
+        let value = match CONFIG_DB.get_config(&name) {
+            Ok(value) => value,
+            Err(_) => return None,
+        };
+        return match DateTime::parse_from_rfc3339(&value) {
+            Ok(time) => Some(time.with_timezone(&Local)),
+            Err(_) => None,
+        };
+    }
+
+    /// Fetch languages from submit page, and cache them into config database.
+    pub async fn refresh_platform_languages(
+        &mut self,
+    ) -> Result<Vec<PlatformLanguage>, OnlineJudgeError> {
+        // Submit page is only available after login.
+        if let Err(info) = self.login().await {
+            return Err(info);
+        }
+        let language_list_url = match self.platform {
+            Platform::Codeforces => Codeforces::build_language_list_url(),
+            Platform::AtCoder => AtCoder::build_language_list_url(),
+        };
+        let resp = match self.http_client.get(&language_list_url).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        let languages = match self.platform {
+            Platform::Codeforces => Codeforces::parse_platform_languages(&resp),
+            Platform::AtCoder => AtCoder::parse_platform_languages(&resp),
+        };
+        let languages = match languages {
+            Ok(languages) => languages,
+            Err(info) => return Err(info.into()),
+        };
+        let value = match serde_json::to_string(&languages) {
+            Ok(value) => value,
+            Err(info) => return Err(info.to_string().into()),
+        };
+        let name = Self::get_languages_config_name(self.platform);
+        if let Err(info) = CONFIG_DB.set_config(&name, &value) {
+            return Err(info.into());
+        }
+        if let Err(info) =
+            CONFIG_DB.set_config(&format!("{}-updated", name), &Local::now().to_rfc3339())
+        {
+            return Err(info.into());
+        }
+        return Ok(languages);
+    }
+
+    /// Difficulties of all problems in the dataset, which is kept in the page cache.
+    pub async fn get_problem_difficulties(
+        &self,
+    ) -> Result<HashMap<String, ProblemDifficulty>, OnlineJudgeError> {
+        let problem_difficulty_url = match self.platform {
+            Platform::Codeforces => Codeforces::build_problem_difficulty_url(),
+            Platform::AtCoder => AtCoder::build_problem_difficulty_url(),
+        };
+        let resp = match self.http_client.get_cached(&problem_difficulty_url).await {
+            Ok(resp) => resp,
+            Err(info) => return Err(info.into()),
+        };
+        return match self.platform {
+            Platform::Codeforces => Codeforces::parse_problem_difficulties(&resp),
+            Platform::AtCoder => AtCoder::parse_problem_difficulties(&resp),
+        }
+        .map_err(OnlineJudgeError::from);
+    }
+
+    fn get_languages_config_name(platform: Platform) -> String {
+        return format!("{}-languages", platform.to_string().to_lowercase());
+    }
+}
+
+/// Online judge replaying the cassette, without proxy and session checks in config database.
+#[cfg(test)]
+fn build_test_online_judge(platform: Platform, path: &std::path::Path) -> OnlineJudge {
+    use crate::utility::cassette::{Cassette, CassetteMode};
+    let endpoint = OnlineJudge::get_endpoint_url(platform);
+    let mut http_client = HttpClient::new("", &endpoint, None).unwrap();
+    http_client.set_cassette(Some(Cassette::new(
+        CassetteMode::Replay,
+        path.to_str().unwrap(),
+    )));
+    return OnlineJudge {
+        platform,
+        username: String::from("ace_test_cassette"),
+        password: String::from("password"),
+        http_client,
+    };
+}
+
+#[tokio::test]
+async fn test_submit_with_cassette() {
+    use crate::utility::cassette::{Cassette, Interaction};
+    let interaction =
+        |method: &str, url: &str, set_cookies: Vec<String>, body: String| Interaction {
+            method: method.to_string(),
+            url: url.to_string(),
+            form: std::collections::HashMap::new(),
+            status: 200,
+            set_cookies,
+            body,
+        };
+    let logged_in = std::fs::read_to_string("assets/atcoder/contest.html").unwrap();
+    let submissions = std::fs::read_to_string("assets/atcoder/recent_submission.html").unwrap();
+    // The submission page is of abc321, it's moved to the submission in the list of arc165.
+    let submission = std::fs::read_to_string("assets/atcoder/submission_page.html")
+        .unwrap()
+        .replace("abc321", "arc165")
+        .replace("46033672", "46003634");
+    let interactions = vec![
+        interaction("GET", "https://atcoder.jp", vec![], String::from("Sign In")),
+        interaction(
+            "GET",
+            "https://atcoder.jp/login",
+            vec![],
+            String::from(r#"var csrfToken = "token""#),
+        ),
+        interaction(
+            "POST",
+            "https://atcoder.jp/login",
+            vec![String::from("REVEL_SESSION=session; Path=/")],
+            String::new(),
+        ),
+        interaction("GET", "https://atcoder.jp", vec![], logged_in),
+        interaction(
+            "GET",
+            "https://atcoder.jp/contests/arc165/submit",
+            vec![],
+            submissions.clone(),
+        ),
+        interaction(
+            "POST",
+            "https://atcoder.jp/contests/arc165/submit",
+            vec![],
+            submissions,
+        ),
+        interaction(
+            "GET",
+            "https://atcoder.jp/contests/arc165/submissions/46003634",
+            vec![],
+            submission,
+        ),
+    ];
+    let path = std::env::temp_dir().join("ace_test_cassette.json");
+    Cassette::save(&path, &interactions).unwrap();
+    let mut oj = build_test_online_judge(Platform::AtCoder, &path);
+    let submission_id = oj
+        .submit("arc165_b", "int main() {}", "5001")
+        .await
+        .unwrap();
+    assert_eq!(submission_id, "46003634");
+    assert!(oj
+        .http_client
+        .save_cookies()
+        .contains("REVEL_SESSION=session"));
+    let result = oj.retrive_result("arc165_b", &submission_id).await.unwrap();
+    assert_eq!(result.verdict_info, "WA");
+    assert!(oj.retrive_result("arc165_b", &submission_id).await.is_err());
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn test_page_cache_of_contest_status() {
+    assert!(!OnlineJudge::is_page_cacheable(&ContestStatus::NotStarted));
+    assert!(!OnlineJudge::is_page_cacheable(&ContestStatus::Running));
+    assert!(OnlineJudge::is_page_cacheable(&ContestStatus::Ended));
+}
+
+#[tokio::test]
+async fn test_get_accepted_submissions_with_cassette() {
+    use crate::utility::cassette::{Cassette, Interaction};
+    let interaction = |url: &str, body: String| Interaction {
+        method: String::from("GET"),
+        url: url.to_string(),
+        form: std::collections::HashMap::new(),
+        status: 200,
+        set_cookies: vec![],
+        body,
+    };
+    let logged_in = std::fs::read_to_string("assets/atcoder/contest.html").unwrap();
+    let submissions = std::fs::read_to_string("assets/atcoder/recent_submission.html").unwrap();
+    let page_url = |page: usize| {
+        AtCoder::build_accepted_submission_list_url("abc321", "ace_test_cassette", page).unwrap()
+    };
+    let interactions = vec![
+        interaction("https://atcoder.jp", logged_in),
+        interaction(&page_url(1), submissions),
+        interaction(&page_url(2), String::new()),
+    ];
+    let path = std::env::temp_dir().join("ace_test_accepted_submissions.json");
+    Cassette::save(&path, &interactions).unwrap();
+    let mut oj = build_test_online_judge(Platform::AtCoder, &path);
+    let submissions = oj.get_accepted_submissions("abc321").await.unwrap();
+    assert_eq!(submissions.len(), 2);
+    let _ = std::fs::remove_file(path);
 }
@@ -1,9 +1,16 @@
-use crate::model::{PostSubmissionInfo, TestCase, Contest, PlatformLanguage};
+use crate::model::{
+    Contest, CustomTestResult, HackInfo, PlatformLanguage, PostSubmissionInfo, ProblemDifficulty,
+    ProblemMetadata, Standings, SubmissionSource, TestCase,
+};
 use std::collections::HashMap;
 
 pub trait OnlineJudgeBehavior {
     fn build_check_login_url() -> String;
     fn check_login(resp: &str) -> bool;
+    /// Handle of the logged in user in the page, `None` if it's not logged in.
+    fn get_login_handle(resp: &str) -> Option<String>;
+    /// Name of the cookie which keeps the logged in session.
+    fn get_session_cookie_name() -> String;
 
     fn build_endpoint_url() -> String;
     fn build_login_page_url() -> String;
@@ -22,15 +29,69 @@ pub trait OnlineJudgeBehavior {
         csrf_token: &str,
     ) -> HashMap<String, String>;
 
+    fn build_custom_test_page_url(contest_identifier: &str) -> String;
+    fn build_custom_test_url(contest_identifier: &str) -> String;
+    fn build_custom_test_request_data(
+        code: &str,
+        lang_id: &str,
+        input: &str,
+        csrf_token: &str,
+    ) -> HashMap<String, String>;
+    fn build_custom_test_result_url(contest_identifier: &str) -> String;
+    /// Form data to get result of custom test, `None` if the result is got by `GET` request.
+    fn build_custom_test_result_request_data(
+        custom_test_id: &str,
+        csrf_token: &str,
+    ) -> Option<HashMap<String, String>>;
+
+    /// Url to post hacks, `None` if the platform doesn't support hacks.
+    fn build_hack_url(contest_identifier: &str) -> Option<String>;
+    fn build_hack_list_url(contest_identifier: &str) -> String;
+    /// Form data of a hack, `input` is the command line of generator if `generator` is set.
+    /// # Arguments
+    /// * `generator` - Source code and language id of the generator.
+    fn build_hack_request_data(
+        submission_id: &str,
+        input: &str,
+        generator: Option<(&str, &str)>,
+        csrf_token: &str,
+    ) -> HashMap<String, String>;
+
     fn build_contest_url(contest_identifier: &str) -> String;
+    fn build_contest_list_url() -> String;
+    /// Submit page which lists languages available now.
+    fn build_language_list_url() -> String;
     fn build_problem_list_url(contest_identifier: &str) -> String;
+    fn build_problem_url(contest_identifier: &str, problem_identifier: &str) -> String;
+    /// Dataset with difficulties of all problems.
+    fn build_problem_difficulty_url() -> String;
     fn build_submit_page_url(contest_identifier: &str) -> String;
     fn build_submit_url(contest_identifier: &str, csrf_token: &str) -> String;
     fn build_submission_url(contest_identifier: &str, submission_id: &str) -> String;
+    fn build_submission_list_url(contest_identifier: &str) -> String;
+    /// Page of accepted submissions of `username` in the contest, pages start from 1.
+    /// `None` if the page is beyond the last one the platform may return.
+    fn build_accepted_submission_list_url(
+        contest_identifier: &str,
+        username: &str,
+        page: usize,
+    ) -> Option<String>;
+    /// Standings of the contest, only rows of `handles` are required if it's not empty.
+    fn build_standings_url(contest_identifier: &str, handles: &[String]) -> String;
+    /// Standings with rows of all official participants.
+    fn build_full_standings_url(contest_identifier: &str) -> String;
+    /// Submissions of a user in all contests, `None` if the platform doesn't provide it.
+    fn build_user_submission_list_url(username: &str) -> Option<String>;
 
     fn parse_problem_list(contest_identifier: &str, resp: &str)
         -> Result<Vec<[String; 2]>, String>;
     fn parse_test_cases(resp: &str) -> Result<Vec<TestCase>, String>;
+    fn parse_statement(resp: &str) -> Result<String, String>;
+    fn parse_problem_metadata(resp: &str) -> Result<ProblemMetadata, String>;
+    fn parse_problem_difficulties(resp: &str)
+        -> Result<HashMap<String, ProblemDifficulty>, String>;
+    /// Key of the problem in the dataset parsed by `parse_problem_difficulties`.
+    fn get_problem_difficulty_key(full_problem_identifier: &str, problem_url: &str) -> String;
     fn parse_submission_page(
         contest_identifier: &str,
         problem_identifier: &str,
@@ -38,8 +99,25 @@ pub trait OnlineJudgeBehavior {
         resp: &str,
     ) -> Result<PostSubmissionInfo, String>;
     fn parse_recent_submission_id(resp: &str) -> Result<String, String>;
+    fn parse_submission_source(resp: &str) -> Result<SubmissionSource, String>;
+    fn parse_submission_list(resp: &str) -> Result<Vec<PostSubmissionInfo>, String>;
+    /// Parse page of `build_accepted_submission_list_url`, only accepted submissions are kept.
+    fn parse_accepted_submission_list(resp: &str) -> Result<Vec<PostSubmissionInfo>, String>;
     fn parse_contest(contest_identifier: &str, resp: &str) -> Result<Contest, String>;
+    fn parse_contest_list(resp: &str) -> Result<Vec<Contest>, String>;
+    /// Parse id of custom test from response of submitting it, empty if the platform has no id.
+    fn parse_custom_test_id(resp: &str) -> Result<String, String>;
+    fn parse_custom_test_result(resp: &str) -> Result<CustomTestResult, String>;
+    /// Parse hacks table, the latest hack comes first.
+    fn parse_hack_list(resp: &str) -> Result<Vec<HackInfo>, String>;
+    /// Parse response of posting a hack, errors of the form are returned.
+    fn parse_hack_result(resp: &str) -> Result<(), String>;
+    /// Parse standings, keep rows of `handles` only if it's not empty.
+    fn parse_standings(resp: &str, handles: &[String]) -> Result<Standings, String>;
+
+    fn parse_platform_languages(resp: &str) -> Result<Vec<PlatformLanguage>, String>;
 
+    /// Languages hard-coded in constants.
     fn get_platform_languages() -> Vec<PlatformLanguage>;
     fn get_csrf_token(resp: &str) -> Result<String, String>;
 }
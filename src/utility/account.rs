@@ -8,6 +8,7 @@ use prettytable::{Cell, Row, Table};
 use crate::constants::PLATFORMS;
 use crate::database::CONFIG_DB;
 use crate::model::Platform;
+use crate::platform::OnlineJudge;
 pub struct AccountUtility {}
 impl AccountUtility {
     pub fn get_platform_from_cmd(platform_from_cmd: Option<Platform>) -> Result<Platform, String> {
@@ -194,6 +195,147 @@ impl AccountUtility {
         };
         return CONFIG_DB.update_password(&account.platform, &account.username, &password);
     }
+
+    /// Import cookies from Netscape `cookies.txt` or a pasted cookie header,
+    /// the account is created without password if it doesn't exist.
+    pub async fn import_cookies(
+        platform_from_cmd: Option<Platform>,
+        file: Option<String>,
+    ) -> Result<String, String> {
+        let platform = match Self::get_platform_from_cmd(platform_from_cmd) {
+            Ok(platform) => platform,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        let username = match Text::new("Enter your username: ")
+            .with_validator(min_length!(1, "Username cannot be empty"))
+            .prompt()
+        {
+            Ok(username) => username,
+            Err(_) => {
+                return Err("Username cannot be empty".to_string());
+            }
+        };
+        let cookies = match file {
+            Some(file) => {
+                let content = match std::fs::read_to_string(&file) {
+                    Ok(content) => content,
+                    Err(info) => {
+                        return Err(format!("Read cookies file {} failed, {}", file, info));
+                    }
+                };
+                let endpoint = OnlineJudge::get_endpoint_url(platform);
+                let host = match reqwest::Url::parse(&endpoint) {
+                    Ok(url) => url.host_str().unwrap_or_default().to_string(),
+                    Err(info) => {
+                        return Err(info.to_string());
+                    }
+                };
+                Self::parse_netscape_cookies(&content, &host)
+            }
+            None => match Text::new("Paste the cookie header: ").prompt() {
+                Ok(header) => Self::parse_cookie_header(&header),
+                Err(_) => {
+                    return Err("Cookie header cannot be empty".to_string());
+                }
+            },
+        };
+        let cookies = match cookies {
+            Ok(cookies) => cookies,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        // Verify the session before saving, so that broken cookies never replace working ones.
+        let (handle, cookies) = match OnlineJudge::check_cookies(platform, &cookies).await {
+            Ok(session) => session,
+            // Hints of blocked requests ask to import cookies, which is running.
+            Err(info) => {
+                return Err(info.to_string());
+            }
+        };
+        if let Err(info) = Self::check_cookies_owner(&username, &handle) {
+            return Err(info);
+        }
+        match CONFIG_DB.is_account_exist(platform, &username) {
+            Ok(true) => {}
+            Ok(false) => {
+                if let Err(info) = CONFIG_DB.add_account(platform, &username, "") {
+                    return Err(info);
+                }
+            }
+            Err(info) => {
+                return Err(info);
+            }
+        }
+        if let Err(info) = CONFIG_DB.save_cookies(platform, &username, &cookies) {
+            return Err(info);
+        }
+        return Ok(username);
+    }
+
+    /// Cookies of another user would break commands matching rows by username,
+    /// such as `ace hack` and `ace standings --friends`.
+    pub fn check_cookies_owner(username: &str, handle: &str) -> Result<(), String> {
+        if !username.eq_ignore_ascii_case(handle) {
+            return Err(format!(
+                "Cookies are of user {}, not {}, check the username or the browser profile.",
+                handle, username
+            ));
+        }
+        return Ok(());
+    }
+
+    /// Parse cookies of `host` from Netscape `cookies.txt`, expired cookies are skipped.
+    /// # Returns
+    /// * `Ok(String)` - Cookies in the form of `name=value; name=value`.
+    pub fn parse_netscape_cookies(content: &str, host: &str) -> Result<String, String> {
+        let now = chrono::Utc::now().timestamp();
+        let mut cookies = Vec::new();
+        for line in content.lines() {
+            // Cookies with HttpOnly flag are commented by this prefix.
+            let line = line.strip_prefix("#HttpOnly_").unwrap_or(line).trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.split('\t').collect::<Vec<_>>();
+            if fields.len() != 7 {
+                return Err(format!("Invalid line in cookies file: {}", line));
+            }
+            let domain = fields[0].trim_start_matches('.');
+            if host != domain && !host.ends_with(&format!(".{}", domain)) {
+                continue;
+            }
+            let expires = fields[4].parse::<i64>().unwrap_or(0);
+            if expires != 0 && expires < now {
+                continue;
+            }
+            cookies.push(format!("{}={}", fields[5], fields[6]));
+        }
+        if cookies.is_empty() {
+            return Err(format!("No cookies of {} found.", host));
+        }
+        return Ok(cookies.join("; "));
+    }
+
+    /// Parse cookie header copied from browser, with or without `Cookie:` prefix.
+    pub fn parse_cookie_header(header: &str) -> Result<String, String> {
+        let header = header.trim();
+        let header = match header.split_once(':') {
+            Some((name, value)) if name.trim().eq_ignore_ascii_case("cookie") => value,
+            _ => header,
+        };
+        let cookies = header
+            .split(';')
+            .map(|x| x.trim())
+            .filter(|x| x.contains('='))
+            .collect::<Vec<_>>();
+        if cookies.is_empty() {
+            return Err(String::from("No cookies found in the header."));
+        }
+        return Ok(cookies.join("; "));
+    }
 }
 
 struct AccountOptions {
@@ -206,3 +348,33 @@ impl Display for AccountOptions {
         f.write_str(format!("{} ({}, {})", self.username, self.platform, self.last_use).as_str())
     }
 }
+
+#[test]
+fn test_parse_netscape_cookies() {
+    let content = "# Netscape HTTP Cookie File\n\
+        \n\
+        #HttpOnly_.codeforces.com\tTRUE\t/\tFALSE\t0\tJSESSIONID\tABCDEF\n\
+        codeforces.com\tFALSE\t/\tFALSE\t4102444800\t39ce7\tCFf1Nq\n\
+        codeforces.com\tFALSE\t/\tFALSE\t1\texpired\tvalue\n\
+        .atcoder.jp\tTRUE\t/\tTRUE\t0\tREVEL_SESSION\txyz\n";
+    let cookies = AccountUtility::parse_netscape_cookies(content, "codeforces.com").unwrap();
+    assert_eq!(cookies, "JSESSIONID=ABCDEF; 39ce7=CFf1Nq");
+    assert!(AccountUtility::parse_netscape_cookies(content, "example.com").is_err());
+}
+
+#[test]
+fn test_parse_cookie_header() {
+    let cookies =
+        AccountUtility::parse_cookie_header("Cookie: JSESSIONID=ABCDEF;39ce7=CFf1Nq; ").unwrap();
+    assert_eq!(cookies, "JSESSIONID=ABCDEF; 39ce7=CFf1Nq");
+    let cookies = AccountUtility::parse_cookie_header("REVEL_SESSION=xyz").unwrap();
+    assert_eq!(cookies, "REVEL_SESSION=xyz");
+}
+
+#[test]
+fn test_check_cookies_owner() {
+    assert!(AccountUtility::check_cookies_owner("dianhsu", "dianhsu").is_ok());
+    assert!(AccountUtility::check_cookies_owner("DianHsu", "dianhsu").is_ok());
+    let info = AccountUtility::check_cookies_owner("tourist", "dianhsu").unwrap_err();
+    assert!(info.contains("dianhsu"));
+}
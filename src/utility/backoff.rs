@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+/// Exponential backoff, the delay is doubled after each attempt until it reaches `max_delay`.
+pub struct Backoff {
+    current_delay: Duration,
+    max_delay: Duration,
+    factor: u32,
+    attempts: u32,
+}
+
+impl Backoff {
+    pub fn new(initial_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            current_delay: initial_delay,
+            max_delay,
+            factor: 2,
+            attempts: 0,
+        }
+    }
+    /// Get the delay before next attempt, and increase the delay for the attempt after it.
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.current_delay;
+        self.current_delay = match self.current_delay.checked_mul(self.factor) {
+            Some(next_delay) => next_delay.min(self.max_delay),
+            None => self.max_delay,
+        };
+        self.attempts += 1;
+        return delay;
+    }
+    /// Sleep for the next delay.
+    pub async fn wait(&mut self) {
+        let delay = self.next_delay();
+        tokio::time::sleep(delay).await;
+    }
+    pub fn attempts(&self) -> u32 {
+        return self.attempts;
+    }
+}
+
+#[test]
+fn test_backoff() {
+    let mut backoff = Backoff::new(Duration::from_millis(500), Duration::from_secs(3));
+    assert_eq!(backoff.next_delay(), Duration::from_millis(500));
+    assert_eq!(backoff.next_delay(), Duration::from_secs(1));
+    assert_eq!(backoff.next_delay(), Duration::from_secs(2));
+    assert_eq!(backoff.next_delay(), Duration::from_secs(3));
+    assert_eq!(backoff.next_delay(), Duration::from_secs(3));
+    assert_eq!(backoff.attempts(), 5);
+}
@@ -0,0 +1,115 @@
+use chrono::{DateTime, Utc};
+
+use crate::model::{Contest, Platform};
+use crate::platform::OnlineJudge;
+
+/// Lines of iCalendar should not be longer than this, excluding the line break.
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Export contests as iCalendar file, see RFC 5545.
+pub struct Calendar {}
+
+impl Calendar {
+    pub fn to_ics(contests: &[(Platform, Contest)]) -> String {
+        let mut lines = vec![
+            String::from("BEGIN:VCALENDAR"),
+            String::from("VERSION:2.0"),
+            String::from("PRODID:-//ace//contests//EN"),
+            String::from("CALSCALE:GREGORIAN"),
+        ];
+        let now = Self::format_time(Utc::now());
+        for (platform, contest) in contests {
+            lines.push(String::from("BEGIN:VEVENT"));
+            lines.push(format!(
+                "UID:{}-{}@ace",
+                platform.to_string().to_lowercase(),
+                contest.identifier
+            ));
+            lines.push(format!("DTSTAMP:{}", now));
+            lines.push(format!("DTSTART:{}", Self::format_time(contest.start_time)));
+            lines.push(format!("DTEND:{}", Self::format_time(contest.end_time)));
+            lines.push(format!(
+                "SUMMARY:{}",
+                Self::escape(&format!("[{}] {}", platform, contest.title))
+            ));
+            lines.push(format!(
+                "URL:{}",
+                OnlineJudge::get_contest_url(*platform, &contest.identifier)
+            ));
+            lines.push(String::from("END:VEVENT"));
+        }
+        lines.push(String::from("END:VCALENDAR"));
+        let lines = lines.iter().map(|x| Self::fold(x)).collect::<Vec<_>>();
+        // Lines are delimited by CRLF in iCalendar.
+        return format!("{}\r\n", lines.join("\r\n"));
+    }
+
+    /// Split lines longer than 75 octets, continuation lines start with a space.
+    /// Multi-byte characters are never split.
+    fn fold(line: &str) -> String {
+        let mut folded = String::new();
+        let mut length = 0;
+        for c in line.chars() {
+            if length + c.len_utf8() > MAX_LINE_OCTETS {
+                folded.push_str("\r\n ");
+                length = 1;
+            }
+            folded.push(c);
+            length += c.len_utf8();
+        }
+        return folded;
+    }
+
+    fn format_time(time: DateTime<Utc>) -> String {
+        return time.format("%Y%m%dT%H%M%SZ").to_string();
+    }
+
+    fn escape(text: &str) -> String {
+        return text
+            .replace('\\', "\\\\")
+            .replace(';', "\\;")
+            .replace(',', "\\,")
+            .replace('\n', "\\n");
+    }
+}
+
+#[test]
+fn test_to_ics() {
+    use chrono::TimeZone;
+
+    use crate::model::ContestStatus;
+    let contest = Contest {
+        identifier: String::from("abc323"),
+        title: String::from("AtCoder Beginner Contest 323, Sponsored"),
+        start_time: Utc.with_ymd_and_hms(2023, 10, 7, 12, 0, 0).unwrap(),
+        end_time: Utc.with_ymd_and_hms(2023, 10, 7, 13, 40, 0).unwrap(),
+        status: ContestStatus::NotStarted,
+        registration: String::new(),
+    };
+    let ics = Calendar::to_ics(&[(Platform::AtCoder, contest)]);
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.contains("UID:atcoder-abc323@ace\r\n"));
+    assert!(ics.contains("DTSTART:20231007T120000Z\r\n"));
+    assert!(ics.contains("DTEND:20231007T134000Z\r\n"));
+    assert!(ics.contains("SUMMARY:[AtCoder] AtCoder Beginner Contest 323\\, Sponsored\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+}
+
+#[test]
+fn test_fold() {
+    assert_eq!(Calendar::fold("SUMMARY:short"), "SUMMARY:short");
+    let line = format!("SUMMARY:{}", "a".repeat(100));
+    let folded = Calendar::fold(&line);
+    let parts = folded.split("\r\n").collect::<Vec<_>>();
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].len(), 75);
+    assert_eq!(parts[1], format!(" {}", "a".repeat(33)));
+    // Multi-byte characters are kept whole, each takes 3 octets.
+    let line = format!(
+        "SUMMARY:{}",
+        "東京海上日動プログラミングコンテスト".repeat(3)
+    );
+    let folded = Calendar::fold(&line);
+    assert!(folded.split("\r\n").all(|x| x.len() <= 75));
+    assert_eq!(folded.replace("\r\n ", ""), line);
+}
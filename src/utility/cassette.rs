@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+use serde_derive::{Deserialize, Serialize};
+
+use super::http_trace::HttpTrace;
+
+/// Record requests into the cassette file in this environment variable.
+const RECORD_ENV: &str = "ACE_CASSETTE_RECORD";
+/// Replay requests from the cassette file in this environment variable, without network.
+const REPLAY_ENV: &str = "ACE_CASSETTE_REPLAY";
+
+lazy_static! {
+    /// Interactions of each cassette file, shared by all clients in the process.
+    /// The flag marks whether the interaction has been replayed.
+    static ref INTERACTIONS: Mutex<HashMap<PathBuf, Vec<(Interaction, bool)>>> =
+        Mutex::new(HashMap::new());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CassetteMode {
+    Record,
+    Replay,
+}
+
+/// A request and its response.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Interaction {
+    pub method: String,
+    pub url: String,
+    /// Form data of the request with secrets redacted, only for reading,
+    /// requests are matched by method and url.
+    #[serde(default)]
+    pub form: HashMap<String, String>,
+    pub status: u16,
+    /// Cookies set by the response and the redirects before it, in the form of `Set-Cookie`.
+    #[serde(default)]
+    pub set_cookies: Vec<String>,
+    pub body: String,
+}
+
+/// Cassette file of request and response pairs, to run requests without network.
+pub struct Cassette {
+    pub mode: CassetteMode,
+    path: PathBuf,
+}
+
+impl Interaction {
+    /// Form data to record, values of passwords and tokens are replaced by `<redacted>`.
+    pub fn redact_form(form: &HashMap<String, String>) -> HashMap<String, String> {
+        return form
+            .iter()
+            .map(|(name, value)| match HttpTrace::is_secret_field(name) {
+                true => (name.clone(), String::from("<redacted>")),
+                false => (name.clone(), value.clone()),
+            })
+            .collect();
+    }
+
+    /// Cookie to record with its value replaced by `redacted`, all values are redacted
+    /// since sites name their session cookies freely, such as `X-User-Sha1` of Codeforces.
+    pub fn redact_set_cookie(set_cookie: &str) -> String {
+        let (pair, attributes) = match set_cookie.split_once(';') {
+            Some((pair, attributes)) => (pair, format!(";{}", attributes)),
+            None => (set_cookie, String::new()),
+        };
+        return match pair.split_once('=') {
+            Some((name, _)) => format!("{}=redacted{}", name, attributes),
+            None => set_cookie.to_string(),
+        };
+    }
+}
+
+impl Cassette {
+    pub fn new(mode: CassetteMode, path: &str) -> Self {
+        Self {
+            mode,
+            path: PathBuf::from(path),
+        }
+    }
+
+    /// Cassette in environment variable `ACE_CASSETTE_RECORD` or `ACE_CASSETTE_REPLAY`.
+    pub fn from_env() -> Option<Self> {
+        if let Ok(path) = std::env::var(REPLAY_ENV) {
+            return Some(Self::new(CassetteMode::Replay, &path));
+        }
+        if let Ok(path) = std::env::var(RECORD_ENV) {
+            return Some(Self::new(CassetteMode::Record, &path));
+        }
+        return None;
+    }
+
+    /// Get the first interaction of the request which is not replayed yet.
+    pub fn replay(&self, method: &str, url: &str) -> Result<Interaction, String> {
+        let mut cassettes = match INTERACTIONS.lock() {
+            Ok(cassettes) => cassettes,
+            Err(_) => return Err(String::from("Cassette is poisoned")),
+        };
+        if !cassettes.contains_key(&self.path) {
+            match Self::load(&self.path) {
+                Ok(interactions) => {
+                    let interactions = interactions.into_iter().map(|x| (x, false)).collect();
+                    cassettes.insert(self.path.clone(), interactions);
+                }
+                Err(info) => return Err(info),
+            }
+        }
+        let interactions = match cassettes.get_mut(&self.path) {
+            Some(interactions) => interactions,
+            None => return Err(String::from("Cassette not loaded")),
+        };
+        return match interactions
+            .iter_mut()
+            .find(|(x, replayed)| !replayed && x.method == method && x.url == url)
+        {
+            Some((interaction, replayed)) => {
+                *replayed = true;
+                Ok(interaction.clone())
+            }
+            None => Err(format!(
+                "No recorded response for {} {} in cassette {}",
+                method,
+                url,
+                self.path.display()
+            )),
+        };
+    }
+
+    /// Append the interaction, and save all interactions recorded in this process.
+    pub fn record(&self, interaction: Interaction) -> Result<(), String> {
+        let mut cassettes = match INTERACTIONS.lock() {
+            Ok(cassettes) => cassettes,
+            Err(_) => return Err(String::from("Cassette is poisoned")),
+        };
+        let interactions = cassettes.entry(self.path.clone()).or_default();
+        interactions.push((interaction, false));
+        let interactions = interactions
+            .iter()
+            .map(|(x, _)| x.clone())
+            .collect::<Vec<_>>();
+        return Self::save(&self.path, &interactions);
+    }
+
+    pub fn load(path: &Path) -> Result<Vec<Interaction>, String> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(info) => {
+                return Err(format!("Read cassette {} failed, {}", path.display(), info));
+            }
+        };
+        return match serde_json::from_str::<Vec<Interaction>>(&content) {
+            Ok(interactions) => Ok(interactions),
+            Err(info) => Err(format!("Invalid cassette {}, {}", path.display(), info)),
+        };
+    }
+
+    pub fn save(path: &Path, interactions: &[Interaction]) -> Result<(), String> {
+        let content = match serde_json::to_string_pretty(interactions) {
+            Ok(content) => content,
+            Err(info) => return Err(info.to_string()),
+        };
+        return match std::fs::write(path, content) {
+            Ok(_) => Ok(()),
+            Err(info) => Err(format!(
+                "Write cassette {} failed, {}",
+                path.display(),
+                info
+            )),
+        };
+    }
+}
+
+#[test]
+fn test_redact_set_cookie() {
+    assert_eq!(
+        Interaction::redact_set_cookie("REVEL_SESSION=abcdef; Path=/; HttpOnly"),
+        "REVEL_SESSION=redacted; Path=/; HttpOnly"
+    );
+    assert_eq!(
+        Interaction::redact_set_cookie("JSESSIONID=ABCDEF"),
+        "JSESSIONID=redacted"
+    );
+}
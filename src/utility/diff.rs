@@ -63,6 +63,34 @@ impl Difference {
     }
 }
 
+impl Difference {
+    /// Compare output token by token, real numbers are equal if the absolute or relative error
+    /// is not greater than `tolerance`. Show difference if they are not the same.
+    pub fn is_same_with_tolerance(expect: &str, result: &str, tolerance: f64) -> bool {
+        let expect_tokens = expect.split_whitespace().collect::<Vec<_>>();
+        let result_tokens = result.split_whitespace().collect::<Vec<_>>();
+        let same = expect_tokens.len() == result_tokens.len()
+            && expect_tokens.iter().zip(result_tokens.iter()).all(
+                |(expect_token, result_token)| {
+                    if expect_token == result_token {
+                        return true;
+                    }
+                    match (expect_token.parse::<f64>(), result_token.parse::<f64>()) {
+                        (Ok(expect_value), Ok(result_value)) => {
+                            let error = (expect_value - result_value).abs();
+                            error <= tolerance || error <= tolerance * expect_value.abs()
+                        }
+                        _ => false,
+                    }
+                },
+            );
+        if !same {
+            Self::is_same(expect, result);
+        }
+        return same;
+    }
+}
+
 #[test]
 fn test_get_diff() {
     let output = "Hello World\nThis is the second line.\nThis is the third.";
@@ -70,3 +98,19 @@ fn test_get_diff() {
     let same = Difference::is_same(output, expect);
     assert_ne!(same, true);
 }
+
+#[test]
+fn test_is_same_with_tolerance() {
+    assert!(Difference::is_same_with_tolerance(
+        "1.000000\n",
+        "1.0000001",
+        1e-6
+    ));
+    assert!(Difference::is_same_with_tolerance(
+        "1000000",
+        "1000000.5",
+        1e-6
+    ));
+    assert!(!Difference::is_same_with_tolerance("1.5", "1.6", 1e-6));
+    assert!(!Difference::is_same_with_tolerance("1 2", "1", 1e-6));
+}
@@ -0,0 +1,248 @@
+use std::io::{Read, Write};
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Interval of checking memory usage of the running command.
+const MEMORY_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+#[derive(Debug, PartialEq)]
+pub enum ExecuteStatus {
+    Finished,
+    TimeLimitExceeded,
+    MemoryLimitExceeded,
+}
+
+#[derive(Debug)]
+pub struct ExecuteResult {
+    pub status: ExecuteStatus,
+    pub stdout: String,
+    /// Wall time of the execution.
+    pub elapsed: Duration,
+    /// Peak memory usage in kilobytes, `None` if it's unavailable on current system.
+    pub memory: Option<u64>,
+}
+
+/// Run the execute command of test cases with time limit and memory limit.
+pub struct Executor {}
+
+impl Executor {
+    /// Run command with input, kill it if it runs longer than `time_limit` milliseconds
+    /// or uses more than `memory_limit` megabytes. Memory of the running command is only
+    /// polled on Linux, on other systems the peak memory is checked after it exits.
+    pub fn execute(
+        execute_command: &str,
+        input: &str,
+        time_limit: Option<u64>,
+        memory_limit: Option<u64>,
+    ) -> Result<ExecuteResult, String> {
+        let mut command = match cfg!(target_os = "windows") {
+            true => Command::new("powershell"),
+            false => Command::new("sh"),
+        };
+        command
+            .args(["-c", execute_command])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped());
+        // Put the command into its own process group, so that we can kill the whole group.
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut command, 0);
+        let start = Instant::now();
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(info) => {
+                return Err(format!("Failed to execute process, {}", info));
+            }
+        };
+        let mut stdin = match child.stdin.take() {
+            Some(stdin) => stdin,
+            None => {
+                return Err("Cannot get stdin".to_string());
+            }
+        };
+        let input = input.to_string();
+        let writer = thread::spawn(move || {
+            let _ = stdin.write_all(input.as_bytes());
+        });
+        let mut stdout = match child.stdout.take() {
+            Some(stdout) => stdout,
+            None => {
+                return Err("Cannot get stdout".to_string());
+            }
+        };
+        let reader = thread::spawn(move || {
+            let mut output = Vec::new();
+            let _ = stdout.read_to_end(&mut output);
+            output
+        });
+
+        let deadline = time_limit.map(|x| start + Duration::from_millis(x));
+        let mut last_memory_poll = start;
+        let (mut status, memory) = loop {
+            match Self::try_wait(&mut child) {
+                Ok(Some(memory)) => break (ExecuteStatus::Finished, memory),
+                Ok(None) => {}
+                Err(info) => {
+                    return Err(info);
+                }
+            }
+            if let Some(deadline) = deadline {
+                if Instant::now() > deadline {
+                    Self::kill(&mut child);
+                    break (ExecuteStatus::TimeLimitExceeded, None);
+                }
+            }
+            if let Some(memory_limit) = memory_limit {
+                if last_memory_poll.elapsed() >= MEMORY_POLL_INTERVAL {
+                    last_memory_poll = Instant::now();
+                    let memory = Self::get_group_memory(child.id());
+                    if let Some(memory) = memory.filter(|x| *x > memory_limit * 1024) {
+                        Self::kill(&mut child);
+                        break (ExecuteStatus::MemoryLimitExceeded, Some(memory));
+                    }
+                }
+            }
+            thread::sleep(Duration::from_millis(5));
+        };
+        let elapsed = start.elapsed();
+        let _ = writer.join();
+        let stdout = match reader.join() {
+            Ok(output) => String::from_utf8_lossy(&output).to_string(),
+            Err(_) => {
+                return Err("Cannot read stdout".to_string());
+            }
+        };
+        // Peak memory between two polls is only known after the command exits.
+        if let (ExecuteStatus::Finished, Some(memory), Some(memory_limit)) =
+            (&status, memory, memory_limit)
+        {
+            if memory > memory_limit * 1024 {
+                status = ExecuteStatus::MemoryLimitExceeded;
+            }
+        }
+        return Ok(ExecuteResult {
+            status,
+            stdout,
+            elapsed,
+            memory,
+        });
+    }
+
+    /// Check if the child has exited without blocking.
+    /// # Returns
+    /// * `Ok(Some(Option<u64>))` - The child has exited, with peak memory in kilobytes if available.
+    /// * `Ok(None)` - The child is still running.
+    #[cfg(unix)]
+    fn try_wait(child: &mut Child) -> Result<Option<Option<u64>>, String> {
+        let mut status: libc::c_int = 0;
+        let mut usage = std::mem::MaybeUninit::<libc::rusage>::zeroed();
+        // wait4 reports the peak memory of the child and all of its waited descendants.
+        let pid = unsafe {
+            libc::wait4(
+                child.id() as libc::pid_t,
+                &mut status,
+                libc::WNOHANG,
+                usage.as_mut_ptr(),
+            )
+        };
+        if pid < 0 {
+            return Err(std::io::Error::last_os_error().to_string());
+        }
+        if pid == 0 {
+            return Ok(None);
+        }
+        let max_rss = unsafe { usage.assume_init() }.ru_maxrss as u64;
+        // ru_maxrss is in bytes on macOS and in kilobytes on other systems.
+        if cfg!(target_os = "macos") {
+            return Ok(Some(Some(max_rss / 1024)));
+        }
+        return Ok(Some(Some(max_rss)));
+    }
+
+    #[cfg(not(unix))]
+    fn try_wait(child: &mut Child) -> Result<Option<Option<u64>>, String> {
+        return match child.try_wait() {
+            Ok(Some(_)) => Ok(Some(None)),
+            Ok(None) => Ok(None),
+            Err(info) => Err(info.to_string()),
+        };
+    }
+
+    /// Resident memory in kilobytes of all processes in the process group of the command,
+    /// the shell and the program it runs are both counted.
+    #[cfg(target_os = "linux")]
+    fn get_group_memory(group_id: u32) -> Option<u64> {
+        let entries = match std::fs::read_dir("/proc") {
+            Ok(entries) => entries,
+            Err(_) => return None,
+        };
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as u64;
+        let group_id = group_id.to_string();
+        let mut memory = 0;
+        for entry in entries.flatten() {
+            let stat = match std::fs::read_to_string(entry.path().join("stat")) {
+                Ok(stat) => stat,
+                Err(_) => continue,
+            };
+            // Fields after the command name are separated by spaces, the name may contain spaces.
+            let fields = match stat.rfind(')') {
+                Some(end) => stat[end + 1..].split_whitespace().collect::<Vec<_>>(),
+                None => continue,
+            };
+            // Process group is the 5th field and resident pages is the 24th field of stat.
+            if fields.get(2) != Some(&group_id.as_str()) {
+                continue;
+            }
+            if let Some(Ok(pages)) = fields.get(21).map(|x| x.parse::<u64>()) {
+                memory += pages * page_size / 1024;
+            }
+        }
+        return Some(memory);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn get_group_memory(_group_id: u32) -> Option<u64> {
+        return None;
+    }
+
+    #[cfg(unix)]
+    fn kill(child: &mut Child) {
+        let pid = child.id() as libc::pid_t;
+        unsafe {
+            libc::kill(-pid, libc::SIGKILL);
+        }
+        let _ = child.wait();
+    }
+
+    #[cfg(not(unix))]
+    fn kill(child: &mut Child) {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_execute() {
+    let result = Executor::execute("cat", "1 2\n", Some(2000), Some(256)).unwrap();
+    assert_eq!(result.status, ExecuteStatus::Finished);
+    assert_eq!(result.stdout, "1 2\n");
+    let result = Executor::execute("sleep 5", "", Some(200), None).unwrap();
+    assert_eq!(result.status, ExecuteStatus::TimeLimitExceeded);
+    assert!(result.elapsed < Duration::from_secs(5));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_execute_memory_limit() {
+    // The shell keeps 128 MB output in memory and then waits, it's killed before the sleep ends.
+    let result = Executor::execute(
+        "x=$(head -c 134217728 /dev/zero | tr '\\0' 'a'); sleep 5",
+        "",
+        Some(10000),
+        Some(64),
+    )
+    .unwrap();
+    assert_eq!(result.status, ExecuteStatus::MemoryLimitExceeded);
+    assert!(result.elapsed < Duration::from_secs(5));
+}
@@ -0,0 +1,173 @@
+use std::fmt::Display;
+
+use reqwest::{header::HeaderMap, StatusCode, Url};
+
+/// Paths of login pages on Codeforces and AtCoder, pages requiring login redirect to them.
+const LOGIN_PATHS: [&str; 2] = ["/enter", "/login"];
+/// Markers of browser checks, which block requests even with a successful status.
+const CHALLENGE_MARKERS: [&str; 4] = [
+    "<title>Just a moment...</title>",
+    "window._cf_chl_opt",
+    "cf-browser-verification",
+    "/aes.min.js",
+];
+/// Markers of maintenance pages, which may be served with a successful status.
+const MAINTENANCE_MARKERS: [&str; 2] = [
+    "Codeforces is temporarily unavailable",
+    "currently under maintenance",
+];
+const CAPTCHA_MARKERS: [&str; 2] = ["g-recaptcha", "h-captcha"];
+
+/// Errors of requests, responses which block ace are classified to explain what happened.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HttpError {
+    /// Cloudflare or the site asks to verify the browser by running javascript.
+    Challenge(String),
+    Captcha(String),
+    /// The page redirects to login, the session is expired or not logged in.
+    LoginRequired(String),
+    RateLimited(String),
+    Maintenance(String),
+    /// Network errors or other unsuccessful responses.
+    Request(String),
+}
+
+impl HttpError {
+    /// Classify the response of the request to `url`, which is redirected to `final_url`.
+    /// # Returns
+    /// * `None` - The response is not a blocking page.
+    pub fn classify(
+        url: &str,
+        final_url: &str,
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Option<Self> {
+        let blocked = matches!(
+            status,
+            StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+        );
+        let host = match Url::parse(url) {
+            Ok(url) => url.host_str().unwrap_or_default().to_string(),
+            Err(_) => url.to_string(),
+        };
+        if blocked && CAPTCHA_MARKERS.iter().any(|x| body.contains(x)) {
+            return Some(Self::Captcha(host));
+        }
+        let cf_mitigated = match headers.get("cf-mitigated") {
+            Some(value) => value.to_str().unwrap_or_default() == "challenge",
+            None => false,
+        };
+        if cf_mitigated || CHALLENGE_MARKERS.iter().any(|x| body.contains(x)) {
+            return Some(Self::Challenge(host));
+        }
+        if MAINTENANCE_MARKERS.iter().any(|x| body.contains(x))
+            || (status == StatusCode::SERVICE_UNAVAILABLE
+                && body.to_lowercase().contains("maintenance"))
+        {
+            return Some(Self::Maintenance(host));
+        }
+        // Codeforces API responds call limit errors with other status codes.
+        if status == StatusCode::TOO_MANY_REQUESTS || body.contains("Call limit exceeded") {
+            return Some(Self::RateLimited(host));
+        }
+        if status == StatusCode::UNAUTHORIZED {
+            return Some(Self::LoginRequired(url.to_string()));
+        }
+        let path = |x: &str| match Url::parse(x) {
+            Ok(x) => x.path().to_string(),
+            Err(_) => String::new(),
+        };
+        let final_path = path(final_url);
+        if path(url) != final_path && LOGIN_PATHS.contains(&final_path.as_str()) {
+            return Some(Self::LoginRequired(url.to_string()));
+        }
+        return None;
+    }
+}
+
+impl HttpError {
+    /// What to do about the error, errors of requests which are not blocked have no hints.
+    pub fn hint(&self) -> Option<&'static str> {
+        return match self {
+            Self::Challenge(_) => Some(
+                "Log in with browser and run `ace account import-cookies`, or retry later.",
+            ),
+            Self::Captcha(_) => Some("Log in with browser and run `ace account import-cookies`."),
+            Self::LoginRequired(_) => Some(
+                "Run `ace account update` to check the password, or `ace account import-cookies` again.",
+            ),
+            Self::RateLimited(_) => Some("Retry later or lower config `rate-limit`."),
+            Self::Maintenance(_) => Some("Retry later."),
+            Self::Request(_) => None,
+        };
+    }
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Challenge(host) => write!(
+                f,
+                "{} responds a browser check (e.g. Cloudflare challenge), which ace cannot pass.",
+                host
+            ),
+            Self::Captcha(host) => write!(f, "{} asks to solve a captcha.", host),
+            Self::LoginRequired(url) => {
+                write!(f, "{} requires login, the session may be expired.", url)
+            }
+            Self::RateLimited(host) => write!(f, "{} is limiting requests.", host),
+            Self::Maintenance(host) => write!(f, "{} is under maintenance.", host),
+            Self::Request(info) => write!(f, "{}", info),
+        }
+    }
+}
+
+#[test]
+fn test_classify() {
+    let url = "https://codeforces.com/contest/1860/problem/A";
+    let headers = HeaderMap::new();
+    let classify = |final_url: &str, status: StatusCode, body: &str| {
+        HttpError::classify(url, final_url, status, &headers, body)
+    };
+    let challenge = std::fs::read_to_string("assets/codeforces/cloudflare_challenge.html").unwrap();
+    assert_eq!(
+        classify(url, StatusCode::FORBIDDEN, &challenge),
+        Some(HttpError::Challenge(String::from("codeforces.com")))
+    );
+    let browser_check = r#"<script type="text/javascript" src="/aes.min.js"></script>"#;
+    assert_eq!(
+        classify(url, StatusCode::OK, browser_check),
+        Some(HttpError::Challenge(String::from("codeforces.com")))
+    );
+    assert_eq!(
+        classify(
+            url,
+            StatusCode::FORBIDDEN,
+            r#"<div class="g-recaptcha"></div>"#
+        ),
+        Some(HttpError::Captcha(String::from("codeforces.com")))
+    );
+    assert_eq!(
+        classify(
+            url,
+            StatusCode::OK,
+            "<h1>Codeforces is temporarily unavailable</h1>"
+        ),
+        Some(HttpError::Maintenance(String::from("codeforces.com")))
+    );
+    assert_eq!(
+        classify(url, StatusCode::TOO_MANY_REQUESTS, ""),
+        Some(HttpError::RateLimited(String::from("codeforces.com")))
+    );
+    assert_eq!(
+        classify(
+            "https://codeforces.com/enter?back=%2Fcontest%2F1860%2Fsubmit",
+            StatusCode::OK,
+            ""
+        ),
+        Some(HttpError::LoginRequired(url.to_string()))
+    );
+    let statement = std::fs::read_to_string("assets/codeforces/test_cases.html").unwrap();
+    assert_eq!(classify(url, StatusCode::OK, &statement), None);
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use lazy_static::lazy_static;
+
+use super::page_cache::PageCache;
+
+/// Values of form fields containing these words are not logged or recorded.
+const REDACTED_FIELDS: [&str; 4] = ["password", "csrf", "token", "session"];
+/// Longer values of form fields, such as source code, are truncated in the log.
+const MAX_FIELD_LENGTH: usize = 64;
+
+lazy_static! {
+    /// Directory of the trace, requests are traced if it's set.
+    static ref TRACE_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+}
+/// Sequence number of traced requests, to keep dumped responses in order.
+static SEQUENCE: AtomicUsize = AtomicUsize::new(0);
+
+/// Trace of requests enabled by `--trace-http`, to debug parsers with the real pages.
+pub struct HttpTrace {}
+
+impl HttpTrace {
+    /// Trace requests of this process into a new directory in `~/.ace/trace`.
+    /// # Returns
+    /// * `Ok(PathBuf)` - Directory of the trace.
+    pub fn enable() -> Result<PathBuf, String> {
+        let trace_dir = match home::home_dir() {
+            Some(home_dir) => home_dir
+                .join(".ace")
+                .join("trace")
+                .join(chrono::Local::now().format("%Y%m%d-%H%M%S").to_string()),
+            None => return Err(String::from("Cannot get home directory")),
+        };
+        if let Err(info) = std::fs::create_dir_all(&trace_dir) {
+            return Err(format!("Create trace directory failed, {}", info));
+        }
+        match TRACE_DIR.lock() {
+            Ok(mut dir) => *dir = Some(trace_dir.clone()),
+            Err(_) => return Err(String::from("Trace directory is poisoned")),
+        }
+        return Ok(trace_dir);
+    }
+
+    pub fn is_enabled() -> bool {
+        return match TRACE_DIR.lock() {
+            Ok(dir) => dir.is_some(),
+            Err(_) => false,
+        };
+    }
+
+    /// Log the request into `trace.log` and stderr, and dump the response body.
+    /// # Arguments
+    /// * `response` - Status and body of the response, or the error if the request failed.
+    pub fn record(
+        method: &str,
+        url: &str,
+        form: Option<&HashMap<String, String>>,
+        response: Result<(u16, &str), &str>,
+        elapsed: Duration,
+    ) {
+        let trace_dir = match TRACE_DIR.lock() {
+            Ok(dir) => match dir.as_ref() {
+                Some(dir) => dir.clone(),
+                None => return,
+            },
+            Err(_) => return,
+        };
+        let sequence = SEQUENCE.fetch_add(1, Ordering::SeqCst) + 1;
+        let status = match response {
+            Ok((status, _)) => status.to_string(),
+            Err(info) => format!("error: {}", info),
+        };
+        let mut line = format!(
+            "#{:03} {} {} {}ms {}",
+            sequence,
+            method,
+            url,
+            elapsed.as_millis(),
+            status
+        );
+        if let Some(form) = form {
+            line.push_str(&format!(" form: {}", Self::redact_form(form)));
+        }
+        eprintln!("[trace] {}", line);
+        if let Ok((_, body)) = response {
+            let extension = match body.trim_start().starts_with(['{', '[']) {
+                true => "json",
+                false => "html",
+            };
+            let body_name = format!(
+                "{:03}_{}_{}.{}",
+                sequence,
+                method,
+                PageCache::get_url_name(url),
+                extension
+            );
+            if let Err(info) = std::fs::write(trace_dir.join(&body_name), body) {
+                log::warn!("Write trace {} failed, {}", body_name, info);
+            }
+            line.push_str(&format!(" -> {}", body_name));
+        }
+        let log_file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(trace_dir.join("trace.log"));
+        if let Err(info) = log_file.and_then(|mut x| writeln!(x, "{}", line)) {
+            log::warn!("Write trace log failed, {}", info);
+        }
+    }
+
+    /// Whether the value of the form field should not be logged or recorded.
+    pub fn is_secret_field(name: &str) -> bool {
+        let name = name.to_lowercase();
+        return REDACTED_FIELDS.iter().any(|x| name.contains(x));
+    }
+
+    /// Form data in sorted order, with secrets redacted and long values truncated.
+    fn redact_form(form: &HashMap<String, String>) -> String {
+        let mut fields = form
+            .iter()
+            .map(|(name, value)| {
+                let value = if Self::is_secret_field(name) {
+                    String::from("<redacted>")
+                } else if value.chars().count() > MAX_FIELD_LENGTH {
+                    format!("<{} bytes>", value.len())
+                } else {
+                    value.clone()
+                };
+                format!("{}={}", name, value)
+            })
+            .collect::<Vec<_>>();
+        fields.sort();
+        return fields.join("&");
+    }
+}
+
+#[test]
+fn test_redact_form() {
+    let mut form = HashMap::new();
+    form.insert(String::from("handleOrEmail"), String::from("tourist"));
+    form.insert(String::from("password"), String::from("secret"));
+    form.insert(String::from("csrf_token"), String::from("abcdef"));
+    form.insert(String::from("sourceCode"), "a".repeat(100));
+    assert_eq!(
+        HttpTrace::redact_form(&form),
+        "csrf_token=<redacted>&handleOrEmail=tourist&password=<redacted>&sourceCode=<100 bytes>"
+    );
+}
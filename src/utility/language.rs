@@ -1,10 +1,76 @@
+use chrono::{Duration, Local};
 use colored::Colorize;
 use inquire::{Confirm, Select, Text};
 use strum::IntoEnumIterator;
 
+use crate::model::{LanguageConfig, PlatformLanguage};
 use crate::platform::OnlineJudge;
 use crate::{constants::ProgramLanguage, database::CONFIG_DB, model::Platform};
 
+/// Keywords to recognize language from description in submit page, checked in order.
+const LANGUAGE_KEYWORDS: [(&str, ProgramLanguage); 44] = [
+    ("c++", ProgramLanguage::Cpp),
+    ("g++", ProgramLanguage::Cpp),
+    ("c#", ProgramLanguage::CSharp),
+    ("f#", ProgramLanguage::FSharp),
+    ("q#", ProgramLanguage::QSharp),
+    ("gcc c", ProgramLanguage::C),
+    ("gnu c", ProgramLanguage::C),
+    ("typescript", ProgramLanguage::TypeScript),
+    ("javascript", ProgramLanguage::JavaScript),
+    ("node.js", ProgramLanguage::JavaScript),
+    ("kotlin", ProgramLanguage::Kotlin),
+    ("scala", ProgramLanguage::Scala),
+    ("java", ProgramLanguage::Java),
+    ("python", ProgramLanguage::Python),
+    ("pypy", ProgramLanguage::Python),
+    ("cython", ProgramLanguage::Python),
+    ("rust", ProgramLanguage::Rust),
+    ("haskell", ProgramLanguage::Haskell),
+    ("ruby", ProgramLanguage::Ruby),
+    ("perl", ProgramLanguage::Perl),
+    ("php", ProgramLanguage::Php),
+    ("ocaml", ProgramLanguage::OCaml),
+    ("delphi", ProgramLanguage::Delphi),
+    ("pascal", ProgramLanguage::Pascal),
+    ("lua", ProgramLanguage::Lua),
+    ("swift", ProgramLanguage::Swift),
+    ("julia", ProgramLanguage::Julia),
+    ("tcl", ProgramLanguage::Tcl),
+    ("factor", ProgramLanguage::Factor),
+    ("text", ProgramLanguage::Text),
+    ("zsh", ProgramLanguage::Zsh),
+    ("bash", ProgramLanguage::Bash),
+    ("powershell", ProgramLanguage::PowerShell),
+    ("cobol", ProgramLanguage::Cobol),
+    ("awk", ProgramLanguage::Awk),
+    ("nim", ProgramLanguage::Nim),
+    ("vim", ProgramLanguage::Vim),
+    ("zig", ProgramLanguage::Zig),
+    ("dart", ProgramLanguage::Dart),
+    ("visual basic", ProgramLanguage::VisualBasic),
+    ("fortran", ProgramLanguage::Fortran),
+    ("lisp", ProgramLanguage::Lisp),
+    ("erlang", ProgramLanguage::Erlang),
+    ("mono", ProgramLanguage::CSharp),
+];
+
+/// Languages with short names, which are recognized by the beginning of description.
+const LANGUAGE_PREFIXES: [(&str, ProgramLanguage); 6] = [
+    ("c ", ProgramLanguage::C),
+    ("d ", ProgramLanguage::D),
+    ("go ", ProgramLanguage::Go),
+    ("r ", ProgramLanguage::R),
+    ("v ", ProgramLanguage::V),
+    ("io ", ProgramLanguage::Io),
+];
+
+/// Unknown languages whose description contains keywords of others, such as `babashka` of Clojure.
+const UNKNOWN_LANGUAGES: [&str; 1] = ["clojure"];
+
+/// Refresh hint is shown if the language list is older than this.
+const LANGUAGE_LIST_EXPIRE_DAYS: i64 = 30;
+
 pub struct LanguageUtility {}
 
 impl LanguageUtility {
@@ -76,6 +142,20 @@ impl LanguageUtility {
                 return Err("Alias cannot be empty".to_string());
             }
         };
+        match OnlineJudge::get_platform_languages_updated_time(platform) {
+            Some(updated_time)
+                if Local::now() - updated_time < Duration::days(LANGUAGE_LIST_EXPIRE_DAYS) => {}
+            _ => {
+                println!(
+                    "{}",
+                    format!(
+                        "Language list of {} may be outdated, run `ace lang refresh` to update it.",
+                        platform
+                    )
+                    .yellow()
+                );
+            }
+        }
         let submit_language_infos = OnlineJudge::get_platform_languages(platform);
         let filtered_submit_language_infos = submit_language_infos
             .iter()
@@ -123,4 +203,171 @@ impl LanguageUtility {
         }
         return res;
     }
+    /// Match options scraped from submit page to program languages,
+    /// by the known languages first and then by keywords in description.
+    /// Options which can't be recognized are dropped.
+    pub fn match_platform_languages(
+        platform: Platform,
+        options: Vec<(String, String)>,
+        known_languages: &[PlatformLanguage],
+    ) -> Vec<PlatformLanguage> {
+        let mut languages = Vec::new();
+        for (id, description) in options {
+            let language = match known_languages.iter().find(|x| x.id == id) {
+                Some(known_language) => Some(known_language.language),
+                None => Self::guess_language(&description),
+            };
+            match language {
+                Some(language) => languages.push(PlatformLanguage {
+                    language,
+                    platform,
+                    id,
+                    description,
+                }),
+                None => {
+                    log::info!("Unknown language {} ({}) of {}", description, id, platform);
+                }
+            }
+        }
+        return languages;
+    }
+
+    pub fn guess_language(description: &str) -> Option<ProgramLanguage> {
+        let description = description.to_lowercase();
+        if UNKNOWN_LANGUAGES.iter().any(|x| description.starts_with(x)) {
+            return None;
+        }
+        for (keyword, language) in LANGUAGE_KEYWORDS.iter() {
+            if description.contains(keyword) {
+                return Some(*language);
+            }
+        }
+        for (prefix, language) in LANGUAGE_PREFIXES.iter() {
+            if description.starts_with(prefix) {
+                return Some(*language);
+            }
+        }
+        return None;
+    }
+
+    /// Suffix of source files of the language.
+    pub fn get_suffix(language: ProgramLanguage) -> &'static str {
+        return match language {
+            ProgramLanguage::C => "c",
+            ProgramLanguage::Cpp => "cpp",
+            ProgramLanguage::Java => "java",
+            ProgramLanguage::Python => "py",
+            ProgramLanguage::Rust => "rs",
+            ProgramLanguage::Kotlin => "kt",
+            ProgramLanguage::Go => "go",
+            ProgramLanguage::CSharp => "cs",
+            ProgramLanguage::D => "d",
+            ProgramLanguage::Ruby => "rb",
+            ProgramLanguage::Scala => "scala",
+            ProgramLanguage::Haskell => "hs",
+            ProgramLanguage::Perl => "pl",
+            ProgramLanguage::Php => "php",
+            ProgramLanguage::OCaml => "ml",
+            ProgramLanguage::Delphi | ProgramLanguage::Pascal => "pas",
+            ProgramLanguage::Lua => "lua",
+            ProgramLanguage::JavaScript => "js",
+            ProgramLanguage::TypeScript => "ts",
+            ProgramLanguage::FSharp => "fs",
+            ProgramLanguage::QSharp => "qs",
+            ProgramLanguage::Swift => "swift",
+            ProgramLanguage::R => "r",
+            ProgramLanguage::Julia => "jl",
+            ProgramLanguage::Tcl => "tcl",
+            ProgramLanguage::Io => "io",
+            ProgramLanguage::Factor => "factor",
+            ProgramLanguage::Text => "txt",
+            ProgramLanguage::Zsh => "zsh",
+            ProgramLanguage::Bash => "sh",
+            ProgramLanguage::PowerShell => "ps1",
+            ProgramLanguage::Cobol => "cob",
+            ProgramLanguage::Awk => "awk",
+            ProgramLanguage::Nim => "nim",
+            ProgramLanguage::Vim => "vim",
+            ProgramLanguage::V => "v",
+            ProgramLanguage::Zig => "zig",
+            ProgramLanguage::Dart => "dart",
+            ProgramLanguage::VisualBasic => "vb",
+            ProgramLanguage::Fortran => "f90",
+            ProgramLanguage::Lisp => "lisp",
+            ProgramLanguage::Erlang => "erl",
+        };
+    }
+
+    /// Language configs of the platform whose submit id is not in `languages` anymore.
+    pub fn get_outdated_language_configs(
+        platform: Platform,
+        languages: &[PlatformLanguage],
+    ) -> Result<Vec<LanguageConfig>, String> {
+        let language_configs = match CONFIG_DB.get_language_config() {
+            Ok(language_configs) => language_configs,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        return Ok(language_configs
+            .into_iter()
+            .filter(|config| config.platform == platform)
+            .filter(|config| !languages.iter().any(|x| x.id == config.submit_id))
+            .collect());
+    }
+}
+
+#[test]
+fn test_guess_language() {
+    assert_eq!(
+        LanguageUtility::guess_language("GNU G++20 13.2 (64 bit, winlibs)"),
+        Some(ProgramLanguage::Cpp)
+    );
+    assert_eq!(
+        LanguageUtility::guess_language("GNU GCC C11 5.1.0"),
+        Some(ProgramLanguage::C)
+    );
+    assert_eq!(
+        LanguageUtility::guess_language("Node.js 15.8.0 (64bit)"),
+        Some(ProgramLanguage::JavaScript)
+    );
+    assert_eq!(
+        LanguageUtility::guess_language("Java 21 64bit"),
+        Some(ProgramLanguage::Java)
+    );
+    assert_eq!(
+        LanguageUtility::guess_language("Python (PyPy 3.10-v7.3.12)"),
+        Some(ProgramLanguage::Python)
+    );
+    assert_eq!(
+        LanguageUtility::guess_language("R (GNU R 4.2.1)"),
+        Some(ProgramLanguage::R)
+    );
+    assert_eq!(
+        LanguageUtility::guess_language("Ruby 3.2.2"),
+        Some(ProgramLanguage::Ruby)
+    );
+    assert_eq!(
+        LanguageUtility::guess_language("Gleam (Gleam 0.30.0)"),
+        None
+    );
+}
+
+#[test]
+fn test_match_platform_languages() {
+    let known_languages = vec![PlatformLanguage {
+        language: ProgramLanguage::Cpp,
+        platform: Platform::AtCoder,
+        id: String::from("5001"),
+        description: String::from("C++ 20 (gcc 12.2)"),
+    }];
+    let options = vec![
+        (String::from("5001"), String::from("C++ 20 (gcc 12.2)")),
+        (String::from("5002"), String::from("Go (go 1.20.6)")),
+        (String::from("5999"), String::from("Gleam (Gleam 0.30.0)")),
+    ];
+    let languages =
+        LanguageUtility::match_platform_languages(Platform::AtCoder, options, &known_languages);
+    assert_eq!(languages.len(), 2);
+    assert_eq!(languages[1].language, ProgramLanguage::Go);
 }
@@ -0,0 +1,272 @@
+use colored::Colorize;
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use scraper::{ElementRef, Node};
+
+lazy_static! {
+    static ref WHITESPACE_RE: Regex = Regex::new(r"\s+").unwrap();
+}
+
+/// Convert problem statements from html to markdown, LaTeX in statements is kept as-is.
+pub struct Markdown {}
+
+impl Markdown {
+    /// Convert a html element to markdown.
+    pub fn from_html(element: ElementRef) -> String {
+        let mut output = String::new();
+        Self::convert_element(element, &mut output);
+        return Self::normalize(&output);
+    }
+
+    fn convert_children(element: ElementRef, output: &mut String) {
+        for child in element.children() {
+            match child.value() {
+                Node::Text(text) => {
+                    output.push_str(&WHITESPACE_RE.replace_all(text, " "));
+                }
+                Node::Element(_) => {
+                    if let Some(child_element) = ElementRef::wrap(child) {
+                        Self::convert_element(child_element, output);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn convert_element(element: ElementRef, output: &mut String) {
+        let value = element.value();
+        let has_class = |name: &str| value.classes().any(|class| class == name);
+        match value.name() {
+            "script" | "style" => {}
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level = value.name()[1..].parse::<usize>().unwrap_or(3);
+                output.push_str(&format!("\n\n{} ", "#".repeat(level)));
+                Self::convert_children(element, output);
+                output.push_str("\n\n");
+            }
+            "p" | "section" | "blockquote" => {
+                output.push_str("\n\n");
+                Self::convert_children(element, output);
+                output.push_str("\n\n");
+            }
+            "div" => {
+                if has_class("section-title") {
+                    output.push_str("\n\n### ");
+                    Self::convert_children(element, output);
+                    output.push_str("\n\n");
+                } else if has_class("title") {
+                    output.push_str("\n\n**");
+                    output.push_str(element.text().collect::<String>().trim());
+                    output.push_str("**\n\n");
+                } else {
+                    output.push('\n');
+                    Self::convert_children(element, output);
+                    output.push('\n');
+                }
+            }
+            "ul" | "ol" => {
+                output.push_str("\n\n");
+                let ordered = value.name() == "ol";
+                let mut index = 0;
+                for child in element.children().filter_map(ElementRef::wrap) {
+                    if child.value().name() != "li" {
+                        continue;
+                    }
+                    index += 1;
+                    let mut item = String::new();
+                    Self::convert_children(child, &mut item);
+                    let prefix = match ordered {
+                        true => format!("{}. ", index),
+                        false => String::from("- "),
+                    };
+                    output.push_str(&prefix);
+                    output.push_str(item.trim());
+                    output.push('\n');
+                }
+                output.push('\n');
+            }
+            "pre" => {
+                output.push_str("\n\n```\n");
+                let text = Self::get_pre_text(element);
+                output.push_str(&text);
+                if !text.ends_with('\n') {
+                    output.push('\n');
+                }
+                output.push_str("```\n\n");
+            }
+            "table" => {
+                output.push_str("\n\n");
+                for (index, row) in element
+                    .select(&scraper::Selector::parse("tr").unwrap())
+                    .enumerate()
+                {
+                    let cells = row
+                        .children()
+                        .filter_map(ElementRef::wrap)
+                        .map(|cell| {
+                            let mut text = String::new();
+                            Self::convert_children(cell, &mut text);
+                            text.trim().replace('\n', " ")
+                        })
+                        .collect::<Vec<_>>();
+                    output.push_str(&format!("| {} |\n", cells.join(" | ")));
+                    if index == 0 {
+                        output.push_str(&format!("|{}\n", " --- |".repeat(cells.len())));
+                    }
+                }
+                output.push('\n');
+            }
+            "hr" => output.push_str("\n\n---\n\n"),
+            "br" => output.push('\n'),
+            "var" => {
+                output.push('$');
+                output.push_str(element.text().collect::<String>().trim());
+                output.push('$');
+            }
+            "code" => {
+                output.push('`');
+                output.push_str(&element.text().collect::<String>());
+                output.push('`');
+            }
+            "strong" | "b" => {
+                output.push_str("**");
+                Self::convert_children(element, output);
+                output.push_str("**");
+            }
+            "em" | "i" => {
+                output.push('*');
+                Self::convert_children(element, output);
+                output.push('*');
+            }
+            "img" => {
+                if let Some(src) = value.attr("src") {
+                    output.push_str(&format!("![]({})", src));
+                }
+            }
+            "a" => match value.attr("href") {
+                Some(href) => {
+                    let mut text = String::new();
+                    Self::convert_children(element, &mut text);
+                    output.push_str(&format!("[{}]({})", text.trim(), href));
+                }
+                None => Self::convert_children(element, output),
+            },
+            "span" => {
+                if has_class("tex-font-style-bf") {
+                    output.push_str("**");
+                    Self::convert_children(element, output);
+                    output.push_str("**");
+                } else if has_class("tex-font-style-it") {
+                    output.push('*');
+                    Self::convert_children(element, output);
+                    output.push('*');
+                } else {
+                    Self::convert_children(element, output);
+                }
+            }
+            _ => Self::convert_children(element, output),
+        }
+    }
+
+    /// Codeforces puts each line of samples into its own div.
+    fn get_pre_text(pre: ElementRef) -> String {
+        let lines = pre
+            .children()
+            .filter_map(ElementRef::wrap)
+            .filter(|x| x.value().name() == "div")
+            .map(|x| x.text().collect::<String>())
+            .collect::<Vec<_>>();
+        if lines.is_empty() {
+            return pre
+                .text()
+                .collect::<String>()
+                .trim_start_matches('\n')
+                .to_string();
+        }
+        return format!("{}\n", lines.join("\n"));
+    }
+
+    /// Trim lines out of code blocks and remove redundant blank lines.
+    fn normalize(markdown: &str) -> String {
+        let mut lines = Vec::new();
+        let mut in_code = false;
+        let mut blank = true;
+        for line in markdown.lines() {
+            if line.trim() == "```" {
+                in_code = !in_code;
+                lines.push(String::from("```"));
+                blank = false;
+                continue;
+            }
+            if in_code {
+                lines.push(line.to_string());
+                continue;
+            }
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                if !blank {
+                    lines.push(String::new());
+                }
+                blank = true;
+            } else {
+                lines.push(trimmed.to_string());
+                blank = false;
+            }
+        }
+        return format!("{}\n", lines.join("\n").trim());
+    }
+
+    /// Render markdown with colors for terminal.
+    pub fn render(markdown: &str) -> String {
+        let bold_re = Regex::new(r"\*\*(.+?)\*\*").unwrap();
+        let tex_re = Regex::new(r"\$\$\$(.+?)\$\$\$|\$(.+?)\$").unwrap();
+        let code_re = Regex::new(r"`(.+?)`").unwrap();
+        let mut output = String::new();
+        let mut in_code = false;
+        for line in markdown.lines() {
+            if line.trim() == "```" {
+                in_code = !in_code;
+                output.push_str(&format!("{}\n", "-".repeat(40).dimmed()));
+                continue;
+            }
+            if in_code {
+                output.push_str(&format!("{}\n", line.bright_cyan()));
+                continue;
+            }
+            if line.starts_with('#') {
+                let title = line.trim_start_matches('#').trim();
+                output.push_str(&format!("{}\n", title.bright_blue().bold().underline()));
+                continue;
+            }
+            let mut rendered = line.to_string();
+            if rendered.starts_with("- ") {
+                rendered = format!("  • {}", &rendered[2..]);
+            }
+            rendered = bold_re
+                .replace_all(&rendered, |caps: &Captures| caps[1].bold().to_string())
+                .to_string();
+            rendered = tex_re
+                .replace_all(&rendered, |caps: &Captures| caps[0].yellow().to_string())
+                .to_string();
+            rendered = code_re
+                .replace_all(&rendered, |caps: &Captures| caps[1].green().to_string())
+                .to_string();
+            output.push_str(&rendered);
+            output.push('\n');
+        }
+        return output;
+    }
+}
+
+#[test]
+fn test_markdown_from_html() {
+    let html = scraper::Html::parse_fragment(
+        "<div><h3>Problem Statement</h3><p>Given <var>N</var> and\n <strong>distinct</strong> $$$a_i$$$.</p><ul><li>one</li><li>two</li></ul><pre><var>N</var>\n1 2\n</pre></div>",
+    );
+    let markdown = Markdown::from_html(html.root_element());
+    assert_eq!(
+        markdown,
+        "### Problem Statement\n\nGiven $N$ and **distinct** $$$a_i$$$.\n\n- one\n- two\n\n```\nN\n1 2\n```\n"
+    );
+}
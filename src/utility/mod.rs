@@ -1,15 +1,26 @@
-use std::path;
+use std::path::{self, Path};
 
 use crate::{
     constants::PLATFORM_MAP,
     database::CONFIG_DB,
-    model::{LanguageConfig, Platform},
+    model::{LanguageConfig, Platform, ProblemMetadata},
 };
 
 pub mod account;
+pub mod backoff;
+pub mod calendar;
+pub mod cassette;
 pub mod language;
 pub mod diff;
+pub mod executor;
 pub mod http_client;
+pub mod http_error;
+pub mod http_trace;
+pub mod markdown;
+pub mod page_cache;
+pub mod problem_type;
+pub mod rate_limiter;
+pub mod virtual_contest;
 pub struct Utility {}
 
 impl Utility {
@@ -60,6 +71,28 @@ impl Utility {
             return Ok((*platform, contest_identifier, problem_identifier));
         }
     }
+    /// Same as `get_identifiers_from_currrent_location`, with current directory and workspace in config.
+    pub fn get_identifiers_from_current_dir() -> Result<(Platform, String, String), String> {
+        let current_dir = match std::env::current_dir() {
+            Ok(current_dir) => current_dir,
+            Err(_) => {
+                return Err("Cannot get current path".to_string());
+            }
+        };
+        let current_dir_str = match current_dir.to_str() {
+            Some(current_dir_str) => current_dir_str,
+            None => {
+                return Err("Can't get current path".to_string());
+            }
+        };
+        let workspace = match CONFIG_DB.get_config("workspace") {
+            Ok(workspace) => workspace,
+            Err(info) => {
+                return Err(info);
+            }
+        };
+        return Self::get_identifiers_from_currrent_location(current_dir_str, &workspace);
+    }
     pub fn get_language_config_by_filename_and_platform(
         filename: &str,
         platform: Platform,
@@ -101,6 +134,40 @@ impl Utility {
         return Ok(test_cases);
     }
 
+    /// Load metadata of the problem, return default metadata if it doesn't exist.
+    pub fn load_problem_metadata(problem_path: &Path) -> Result<ProblemMetadata, String> {
+        let metadata_path = problem_path.join("metadata.json");
+        if !metadata_path.exists() {
+            return Ok(ProblemMetadata::default());
+        }
+        let content = match std::fs::read_to_string(metadata_path) {
+            Ok(content) => content,
+            Err(info) => {
+                return Err(info.to_string());
+            }
+        };
+        return match serde_json::from_str::<ProblemMetadata>(&content) {
+            Ok(metadata) => Ok(metadata),
+            Err(info) => Err(format!("Invalid metadata file, {}", info)),
+        };
+    }
+
+    pub fn save_problem_metadata(
+        problem_path: &Path,
+        metadata: &ProblemMetadata,
+    ) -> Result<(), String> {
+        let content = match serde_json::to_string_pretty(metadata) {
+            Ok(content) => content,
+            Err(info) => {
+                return Err(info.to_string());
+            }
+        };
+        return match std::fs::write(problem_path.join("metadata.json"), content) {
+            Ok(_) => Ok(()),
+            Err(info) => Err(info.to_string()),
+        };
+    }
+
     pub fn find_source_code_filename_from_directory(directory: &str) -> Vec<String> {
         let res = match std::fs::read_dir(directory) {
             Ok(files) => files
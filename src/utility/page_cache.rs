@@ -0,0 +1,183 @@
+use std::path::PathBuf;
+
+use chrono::Utc;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::database::CONFIG_DB;
+
+/// Seconds to use a cached page without revalidation if config `cache-ttl` is not set.
+const DEFAULT_CACHE_TTL: i64 = 86400;
+
+/// A fetched page with validators to revalidate it.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CachedPage {
+    pub url: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    /// Unix timestamp in seconds when the page is fetched or revalidated.
+    pub fetched_at: i64,
+    pub body: String,
+}
+
+#[derive(Debug, Default)]
+pub struct CacheStats {
+    pub entries: u64,
+    /// Total size in bytes.
+    pub size: u64,
+    /// Unix timestamp of the oldest fetched page.
+    pub oldest: Option<i64>,
+}
+
+/// Cache of pages in `~/.ace/cache`, one file for each url.
+pub struct PageCache {}
+
+impl PageCache {
+    pub fn get_cache_dir() -> Result<PathBuf, String> {
+        return match home::home_dir() {
+            Some(home_dir) => Ok(home_dir.join(".ace").join("cache")),
+            None => Err(String::from("Cannot get home directory")),
+        };
+    }
+
+    pub fn load(url: &str) -> Option<CachedPage> {
+        let path = match Self::get_cache_dir() {
+            Ok(cache_dir) => cache_dir.join(Self::get_file_name(url)),
+            Err(_) => return None,
+        };
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(_) => return None,
+        };
+        return match serde_json::from_str::<CachedPage>(&content) {
+            // Different urls may have the same file name.
+            Ok(page) if page.url == url => Some(page),
+            Ok(_) => None,
+            Err(info) => {
+                log::warn!("Invalid cached page of {}, {}", url, info);
+                None
+            }
+        };
+    }
+
+    pub fn save(page: &CachedPage) -> Result<(), String> {
+        let cache_dir = match Self::get_cache_dir() {
+            Ok(cache_dir) => cache_dir,
+            Err(info) => return Err(info),
+        };
+        if let Err(info) = std::fs::create_dir_all(&cache_dir) {
+            return Err(format!("Create cache directory failed, {}", info));
+        }
+        let content = match serde_json::to_string(page) {
+            Ok(content) => content,
+            Err(info) => return Err(info.to_string()),
+        };
+        return match std::fs::write(cache_dir.join(Self::get_file_name(&page.url)), content) {
+            Ok(_) => Ok(()),
+            Err(info) => Err(format!("Write cache failed, {}", info)),
+        };
+    }
+
+    /// Whether the page can be used without revalidation.
+    pub fn is_fresh(page: &CachedPage) -> bool {
+        return Utc::now().timestamp() - page.fetched_at < Self::get_ttl();
+    }
+
+    /// Seconds in config `cache-ttl`.
+    fn get_ttl() -> i64 {
+        return match CONFIG_DB.get_config("cache-ttl") {
+            Ok(value) => match value.trim().parse::<i64>() {
+                Ok(ttl) => ttl,
+                Err(_) => {
+                    log::warn!("Invalid config cache-ttl: {}", value);
+                    DEFAULT_CACHE_TTL
+                }
+            },
+            Err(_) => DEFAULT_CACHE_TTL,
+        };
+    }
+
+    /// Remove all cached pages.
+    /// # Returns
+    /// * `Ok(u64)` - Number of removed pages.
+    pub fn clear() -> Result<u64, String> {
+        let cache_dir = match Self::get_cache_dir() {
+            Ok(cache_dir) => cache_dir,
+            Err(info) => return Err(info),
+        };
+        let entries = match std::fs::read_dir(&cache_dir) {
+            Ok(entries) => entries,
+            Err(_) => return Ok(0),
+        };
+        let mut count = 0;
+        for entry in entries.filter_map(|x| x.ok()) {
+            if let Err(info) = std::fs::remove_file(entry.path()) {
+                return Err(format!(
+                    "Remove {} failed, {}",
+                    entry.path().display(),
+                    info
+                ));
+            }
+            count += 1;
+        }
+        return Ok(count);
+    }
+
+    pub fn stats() -> Result<CacheStats, String> {
+        let cache_dir = match Self::get_cache_dir() {
+            Ok(cache_dir) => cache_dir,
+            Err(info) => return Err(info),
+        };
+        let mut stats = CacheStats::default();
+        let entries = match std::fs::read_dir(&cache_dir) {
+            Ok(entries) => entries,
+            Err(_) => return Ok(stats),
+        };
+        for entry in entries.filter_map(|x| x.ok()) {
+            let metadata = match entry.metadata() {
+                Ok(metadata) => metadata,
+                Err(_) => continue,
+            };
+            stats.entries += 1;
+            stats.size += metadata.len();
+            let modified = match metadata.modified() {
+                Ok(modified) => chrono::DateTime::<Utc>::from(modified).timestamp(),
+                Err(_) => continue,
+            };
+            stats.oldest = Some(stats.oldest.map_or(modified, |x| x.min(modified)));
+        }
+        return Ok(stats);
+    }
+
+    /// Readable file name of the url, such as `atcoder.jp_contests_abc300_tasks.json`.
+    fn get_file_name(url: &str) -> String {
+        return format!("{}.json", Self::get_url_name(url));
+    }
+
+    /// The url without scheme, characters not allowed in file names are replaced by `_`.
+    pub fn get_url_name(url: &str) -> String {
+        let name = url
+            .split_once("://")
+            .map_or(url, |(_, x)| x)
+            .chars()
+            .map(
+                |x| match x.is_ascii_alphanumeric() || x == '.' || x == '-' {
+                    true => x,
+                    false => '_',
+                },
+            )
+            .collect::<String>();
+        return name.trim_end_matches('_').to_string();
+    }
+}
+
+#[test]
+fn test_get_file_name() {
+    assert_eq!(
+        PageCache::get_file_name("https://atcoder.jp/contests/abc300/tasks"),
+        "atcoder.jp_contests_abc300_tasks.json"
+    );
+    assert_eq!(
+        PageCache::get_file_name("https://codeforces.com/contest/1860/problem/A?locale=en"),
+        "codeforces.com_contest_1860_problem_A_locale_en.json"
+    );
+}
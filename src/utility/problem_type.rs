@@ -0,0 +1,78 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref INTERACTIVE_RE: Regex = Regex::new(
+        r#"(?i)this is an interactive (task|problem)|インタラクティブ|### Interaction|### Input and Output"#
+    )
+    .unwrap();
+    /// Japanese statements are matched only when answers or solutions are multiple,
+    /// other things like `複数ある` are common in statements of normal problems.
+    static ref SPECIAL_JUDGE_RE: Regex = Regex::new(
+        r#"(?i)(multiple|several) (possible |correct |valid |optimal )?(answers|solutions)|(print|output|accept) any (of them|one of them|such|valid|correct|answer|solution|optimal)|(答え|解)が複数"#
+    )
+    .unwrap();
+    static ref ERROR_TOLERANCE_RE: Regex = Regex::new(
+        r#"(?i)((absolute|relative) error|絶対誤差|相対誤差)[^.。]*?10\^\{?-(\d+)\}?"#
+    )
+    .unwrap();
+}
+
+/// Detect the type of problems from statements in markdown, shared by Codeforces and AtCoder.
+pub struct ProblemType {}
+
+impl ProblemType {
+    pub fn is_interactive(statement: &str) -> bool {
+        return INTERACTIVE_RE.is_match(statement);
+    }
+    pub fn is_special_judge(statement: &str) -> bool {
+        return SPECIAL_JUDGE_RE.is_match(statement);
+    }
+    /// Get allowed error from statement like `absolute or relative error doesn't exceed $$$10^{-6}$$$`.
+    pub fn get_error_tolerance(statement: &str) -> Option<f64> {
+        return match ERROR_TOLERANCE_RE.captures(statement) {
+            Some(caps) => match caps[3].parse::<i32>() {
+                Ok(exponent) => Some(10f64.powi(-exponent)),
+                Err(_) => None,
+            },
+            None => None,
+        };
+    }
+}
+
+#[test]
+fn test_problem_type() {
+    assert!(ProblemType::is_interactive(
+        "This is an interactive problem."
+    ));
+    assert!(ProblemType::is_interactive("This is an interactive task"));
+    assert!(ProblemType::is_special_judge(
+        "If there are multiple answers, print any of them."
+    ));
+    assert!(ProblemType::is_special_judge(
+        "答えが複数存在する場合、どれを出力しても正解とみなされます。"
+    ));
+    assert!(ProblemType::is_special_judge(
+        "解が複数ある場合、どれを出力してもよい。"
+    ));
+    assert!(!ProblemType::is_special_judge("Print a single integer."));
+    assert!(!ProblemType::is_special_judge(
+        "同じ値が複数ある場合もあります。"
+    ));
+    assert_eq!(
+        ProblemType::get_error_tolerance(
+            "Your answer is considered correct if its absolute or relative error does not exceed $$$10^{-6}$$$."
+        ),
+        Some(1e-6)
+    );
+    assert_eq!(
+        ProblemType::get_error_tolerance(
+            "Your output is considered correct when its absolute or relative error from the true value is at most $10^{-6}$."
+        ),
+        Some(1e-6)
+    );
+    assert_eq!(
+        ProblemType::get_error_tolerance("真の値との絶対誤差または相対誤差が $10^{-9}$ 以下"),
+        Some(1e-9)
+    );
+}
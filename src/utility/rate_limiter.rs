@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use lazy_static::lazy_static;
+
+use crate::database::CONFIG_DB;
+
+/// Requests per second to each host if config `rate-limit` is not set.
+const DEFAULT_RATE_LIMIT: f64 = 2.0;
+
+lazy_static! {
+    /// Buckets are shared by all clients, so that requests of different commands
+    /// in the same process are limited together.
+    static ref BUCKETS: Mutex<HashMap<String, TokenBucket>> = Mutex::new(HashMap::new());
+}
+
+/// Token bucket, which allows bursts of `capacity` requests and refills `rate` tokens per second.
+#[derive(Debug)]
+pub struct TokenBucket {
+    capacity: f64,
+    rate: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    pub fn new(rate: f64, capacity: f64, now: Instant) -> Self {
+        Self {
+            capacity,
+            rate,
+            tokens: capacity,
+            updated: now,
+        }
+    }
+    /// Take a token, and get the time to wait before it's available.
+    /// The token is reserved even if it's not available yet, so that waiting requests keep their order.
+    pub fn acquire(&mut self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.updated = now;
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            return Duration::ZERO;
+        }
+        return Duration::from_secs_f64(-self.tokens / self.rate);
+    }
+}
+
+pub struct RateLimiter {}
+
+impl RateLimiter {
+    /// Wait until a request to the host is allowed.
+    pub async fn acquire(host: &str) {
+        let delay = {
+            let mut buckets = match BUCKETS.lock() {
+                Ok(buckets) => buckets,
+                Err(_) => return,
+            };
+            let now = Instant::now();
+            if !buckets.contains_key(host) {
+                let rate = Self::get_rate_limit(host);
+                if rate <= 0.0 {
+                    return;
+                }
+                buckets.insert(host.to_string(), TokenBucket::new(rate, rate.ceil(), now));
+            }
+            match buckets.get_mut(host) {
+                Some(bucket) => bucket.acquire(now),
+                None => Duration::ZERO,
+            }
+        };
+        if !delay.is_zero() {
+            log::info!("Rate limit of {}, wait {:?}", host, delay);
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Requests per second to the host, from config `rate-limit-<host>` or `rate-limit`.
+    /// Zero or negative value disables the limiter.
+    fn get_rate_limit(host: &str) -> f64 {
+        for name in [format!("rate-limit-{}", host), String::from("rate-limit")] {
+            if let Ok(value) = CONFIG_DB.get_config(&name) {
+                match value.trim().parse::<f64>() {
+                    Ok(rate) => return rate,
+                    Err(_) => log::warn!("Invalid config {}: {}", name, value),
+                }
+            }
+        }
+        return DEFAULT_RATE_LIMIT;
+    }
+}
+
+#[test]
+fn test_token_bucket() {
+    let now = Instant::now();
+    let mut bucket = TokenBucket::new(2.0, 2.0, now);
+    assert_eq!(bucket.acquire(now), Duration::ZERO);
+    assert_eq!(bucket.acquire(now), Duration::ZERO);
+    assert_eq!(bucket.acquire(now), Duration::from_millis(500));
+    assert_eq!(bucket.acquire(now), Duration::from_secs(1));
+    let later = now + Duration::from_secs(2);
+    assert_eq!(bucket.acquire(later), Duration::ZERO);
+    assert_eq!(bucket.acquire(later), Duration::ZERO);
+    assert_eq!(bucket.acquire(later), Duration::from_millis(500));
+}
@@ -0,0 +1,311 @@
+use chrono::Utc;
+
+use crate::database::CONFIG_DB;
+use crate::model::{Platform, ScoringRule, Standings, VirtualContest, VirtualSubmission};
+
+/// Config name to save the running virtual contest.
+const VIRTUAL_CONTEST_CONFIG_NAME: &str = "virtual-contest";
+
+/// Result of a problem in virtual contest.
+#[derive(Debug)]
+pub struct ProblemSummary {
+    pub problem: String,
+    /// Seconds since the start when the problem is accepted.
+    pub accepted_elapsed: Option<i64>,
+    /// Rejected attempts before the first accepted one.
+    pub rejected: u32,
+    pub score: f64,
+}
+
+#[derive(Debug)]
+pub struct VirtualSummary {
+    pub problems: Vec<ProblemSummary>,
+    pub score: f64,
+    /// Penalty in seconds.
+    pub penalty: i64,
+}
+
+pub struct VirtualContestUtility {}
+
+impl VirtualContestUtility {
+    /// Get the running virtual contest, `None` if there is no one.
+    pub fn load() -> Result<Option<VirtualContest>, String> {
+        let value = match CONFIG_DB.get_config(VIRTUAL_CONTEST_CONFIG_NAME) {
+            Ok(value) => value,
+            Err(_) => return Ok(None),
+        };
+        return match serde_json::from_str::<VirtualContest>(&value) {
+            Ok(virtual_contest) => Ok(Some(virtual_contest)),
+            Err(info) => Err(format!("Load virtual contest failed, {}", info)),
+        };
+    }
+
+    pub fn save(virtual_contest: &VirtualContest) -> Result<(), String> {
+        let value = match serde_json::to_string(virtual_contest) {
+            Ok(value) => value,
+            Err(info) => return Err(info.to_string()),
+        };
+        return CONFIG_DB.set_config(VIRTUAL_CONTEST_CONFIG_NAME, &value);
+    }
+
+    pub fn remove() -> Result<(), String> {
+        return CONFIG_DB.remove_config(vec![String::from(VIRTUAL_CONTEST_CONFIG_NAME)]);
+    }
+
+    /// Record the submission if it's made in the running virtual contest.
+    /// # Returns
+    /// * `Ok(Some(VirtualContest))` - The virtual contest with the submission recorded.
+    /// * `Ok(None)` - The submission doesn't belong to a running virtual contest.
+    pub fn record_submission(
+        platform: Platform,
+        full_problem_identifier: &str,
+        submission_id: &str,
+        submit_time: i64,
+        verdict_info: &str,
+        judged: bool,
+    ) -> Result<Option<VirtualContest>, String> {
+        let mut virtual_contest = match Self::load() {
+            Ok(Some(virtual_contest)) => virtual_contest,
+            Ok(None) => return Ok(None),
+            Err(info) => return Err(info),
+        };
+        let contest_identifier = full_problem_identifier
+            .split('_')
+            .next()
+            .unwrap_or_default();
+        let elapsed = submit_time - virtual_contest.start_time;
+        if virtual_contest.platform != platform
+            || !virtual_contest
+                .contest_identifier
+                .eq_ignore_ascii_case(contest_identifier)
+            || elapsed > virtual_contest.duration
+        {
+            return Ok(None);
+        }
+        virtual_contest.submissions.push(VirtualSubmission {
+            submission_id: submission_id.to_string(),
+            problem_identifier: full_problem_identifier.to_string(),
+            elapsed,
+            verdict_info: verdict_info.to_string(),
+            judged,
+        });
+        if let Err(info) = Self::save(&virtual_contest) {
+            return Err(info);
+        }
+        return Ok(Some(virtual_contest));
+    }
+
+    pub fn get_elapsed(virtual_contest: &VirtualContest) -> i64 {
+        let elapsed = Utc::now().timestamp() - virtual_contest.start_time;
+        return elapsed.min(virtual_contest.duration);
+    }
+
+    pub fn is_accepted(verdict_info: &str) -> bool {
+        return matches!(verdict_info, "Accepted" | "AC");
+    }
+
+    /// Compilation errors are not counted as rejected attempts on both platforms.
+    fn is_ignored(verdict_info: &str) -> bool {
+        return matches!(verdict_info, "Compilation error" | "CE");
+    }
+
+    /// Calculate score and penalty of submissions by the scoring rule of the contest.
+    pub fn summarize(virtual_contest: &VirtualContest) -> VirtualSummary {
+        let mut problems = Vec::new();
+        for (index, problem) in virtual_contest.problems.iter().enumerate() {
+            let mut summary = ProblemSummary {
+                problem: problem.clone(),
+                accepted_elapsed: None,
+                rejected: 0,
+                score: 0.0,
+            };
+            let submissions = virtual_contest.submissions.iter().filter(|x| {
+                match x.problem_identifier.rsplit_once('_') {
+                    Some((_, problem_identifier)) => {
+                        problem_identifier.eq_ignore_ascii_case(problem)
+                    }
+                    None => false,
+                }
+            });
+            for submission in submissions {
+                if !submission.judged || Self::is_ignored(&submission.verdict_info) {
+                    continue;
+                }
+                if Self::is_accepted(&submission.verdict_info) {
+                    summary.accepted_elapsed = Some(submission.elapsed);
+                    break;
+                }
+                summary.rejected += 1;
+            }
+            if let Some(elapsed) = summary.accepted_elapsed {
+                let points = virtual_contest
+                    .problem_points
+                    .get(index)
+                    .copied()
+                    .unwrap_or_default();
+                summary.score = match virtual_contest.rule {
+                    ScoringRule::Icpc | ScoringRule::AtCoder => points,
+                    ScoringRule::Codeforces => {
+                        let minutes = (elapsed / 60) as f64;
+                        let decreased = points - points / 250.0 * minutes;
+                        (decreased - 50.0 * summary.rejected as f64).max(points * 0.3)
+                    }
+                };
+            }
+            problems.push(summary);
+        }
+        let solved = problems.iter().filter(|x| x.accepted_elapsed.is_some());
+        let penalty = match virtual_contest.rule {
+            ScoringRule::Icpc => solved
+                .map(|x| {
+                    (x.accepted_elapsed.unwrap_or_default() / 60 + 10 * x.rejected as i64) * 60
+                })
+                .sum(),
+            ScoringRule::Codeforces => 0,
+            ScoringRule::AtCoder => {
+                let solved = solved.collect::<Vec<_>>();
+                let last_accepted = solved
+                    .iter()
+                    .filter_map(|x| x.accepted_elapsed)
+                    .max()
+                    .unwrap_or_default();
+                let rejected = solved.iter().map(|x| x.rejected as i64).sum::<i64>();
+                last_accepted + rejected * 300
+            }
+        };
+        return VirtualSummary {
+            score: problems.iter().map(|x| x.score).sum(),
+            problems,
+            penalty,
+        };
+    }
+
+    /// Rank in the standings if the summary was an official result.
+    pub fn get_rank(standings: &Standings, summary: &VirtualSummary) -> u64 {
+        let better = standings
+            .rows
+            .iter()
+            .filter(|row| {
+                let score = row.score.parse::<f64>().unwrap_or_default();
+                let penalty = Self::parse_penalty(&row.penalty);
+                score > summary.score + 1e-6
+                    || ((score - summary.score).abs() <= 1e-6 && penalty < summary.penalty)
+            })
+            .count();
+        return better as u64 + 1;
+    }
+
+    /// Parse penalty in standings to seconds, such as `58` in minutes or `35:00`.
+    pub fn parse_penalty(penalty: &str) -> i64 {
+        return penalty
+            .split(':')
+            .map(|x| x.trim().parse::<i64>().unwrap_or_default())
+            .fold(0, |acc, x| acc * 60 + x)
+            * match penalty.contains(':') {
+                true => 1,
+                false => 60,
+            };
+    }
+
+    /// Format seconds as `h:mm:ss`.
+    pub fn format_elapsed(seconds: i64) -> String {
+        return format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        );
+    }
+}
+
+#[cfg(test)]
+fn build_virtual_contest(rule: ScoringRule, problem_points: Vec<f64>) -> VirtualContest {
+    let submission =
+        |problem_identifier: &str, elapsed: i64, verdict_info: &str| VirtualSubmission {
+            submission_id: String::new(),
+            problem_identifier: problem_identifier.to_string(),
+            elapsed,
+            verdict_info: verdict_info.to_string(),
+            judged: true,
+        };
+    VirtualContest {
+        platform: Platform::Codeforces,
+        contest_identifier: String::from("1860"),
+        title: String::new(),
+        start_time: 0,
+        duration: 7200,
+        problems: vec![String::from("A"), String::from("B"), String::from("C")],
+        problem_points,
+        rule,
+        submissions: vec![
+            submission("1860_a", 300, "Accepted"),
+            submission("1860_b", 600, "Wrong answer on test 2"),
+            submission("1860_b", 700, "Compilation error"),
+            submission("1860_b", 1200, "Accepted"),
+            submission("1860_c", 1800, "Time limit exceeded on test 5"),
+        ],
+    }
+}
+
+#[test]
+fn test_summarize() {
+    let summary = VirtualContestUtility::summarize(&build_virtual_contest(
+        ScoringRule::Icpc,
+        vec![1.0, 1.0, 1.0],
+    ));
+    assert_eq!(summary.score, 2.0);
+    assert_eq!(summary.penalty, (5 + 20 + 10) * 60);
+    assert_eq!(summary.problems[1].rejected, 1);
+    assert_eq!(summary.problems[2].accepted_elapsed, None);
+    let summary = VirtualContestUtility::summarize(&build_virtual_contest(
+        ScoringRule::AtCoder,
+        vec![100.0, 200.0, 300.0],
+    ));
+    assert_eq!(summary.score, 300.0);
+    assert_eq!(summary.penalty, 1200 + 300);
+    let summary = VirtualContestUtility::summarize(&build_virtual_contest(
+        ScoringRule::Codeforces,
+        vec![500.0, 1000.0, 1500.0],
+    ));
+    assert_eq!(summary.score, 490.0 + 870.0);
+    assert_eq!(summary.penalty, 0);
+}
+
+#[test]
+fn test_get_rank() {
+    let row = |rank: u64, score: &str, penalty: &str| crate::model::StandingsRow {
+        rank,
+        handle: String::from("tourist"),
+        score: score.to_string(),
+        penalty: penalty.to_string(),
+        problem_results: Vec::new(),
+    };
+    let standings = Standings {
+        problems: vec![String::from("A"), String::from("B")],
+        problem_points: vec![1.0, 1.0],
+        rule: ScoringRule::Icpc,
+        rows: vec![row(1, "3", "25"), row(2, "2", "30"), row(3, "2", "58")],
+    };
+    let summary = VirtualSummary {
+        problems: Vec::new(),
+        score: 2.0,
+        penalty: 35 * 60,
+    };
+    assert_eq!(VirtualContestUtility::get_rank(&standings, &summary), 3);
+    // Penalty of AtCoder rows includes 5 minutes for each rejected submission,
+    // such as 35:00 elapsed with 2 rejected submissions.
+    let standings = Standings {
+        problems: vec![String::from("A"), String::from("B")],
+        problem_points: vec![100.0, 200.0],
+        rule: ScoringRule::AtCoder,
+        rows: vec![row(1, "300", "45:00")],
+    };
+    let summary = VirtualSummary {
+        problems: Vec::new(),
+        score: 300.0,
+        penalty: 40 * 60,
+    };
+    assert_eq!(VirtualContestUtility::get_rank(&standings, &summary), 1);
+    assert_eq!(VirtualContestUtility::parse_penalty("35:00"), 35 * 60);
+    assert_eq!(VirtualContestUtility::parse_penalty("58"), 58 * 60);
+}